@@ -393,6 +393,33 @@ mod tests {
         assert_eq!(deserialized, Amount::raw(123));
     }
 
+    #[test]
+    fn serde_deserialize_max() {
+        let deserialized: Amount =
+            serde_json::from_str("\"340282366920938463463374607431768211455\"").unwrap();
+        assert_eq!(deserialized, Amount::MAX);
+    }
+
+    #[test]
+    fn serde_deserialize_zero() {
+        let deserialized: Amount = serde_json::from_str("\"0\"").unwrap();
+        assert_eq!(deserialized, Amount::zero());
+    }
+
+    #[test]
+    fn serde_deserialize_overflow() {
+        let result = serde_json::from_str::<Amount>("\"340282366920938463463374607431768211456\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serde_deserialize_malformed() {
+        let error = serde_json::from_str::<Amount>("\"12abc\"").unwrap_err();
+        assert!(error.to_string().contains("a 128bit decimal string"));
+        assert!(serde_json::from_str::<Amount>("\"1.5\"").is_err());
+        assert!(serde_json::from_str::<Amount>("\"-1\"").is_err());
+    }
+
     #[test]
     fn implements_debug() {
         let formatted = format!("{:?}", Amount::raw(123));
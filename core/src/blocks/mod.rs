@@ -26,10 +26,13 @@ pub use state_block::{EpochBlockArgs, JsonStateBlock, StateBlock, StateBlockArgs
 mod builders;
 pub use builders::*;
 
+mod signature_verification;
+pub use signature_verification::verify_block_signatures_batch;
+
 use crate::{
     utils::{BufferWriter, Deserialize, MemoryStream, Stream},
     Account, Amount, BlockHash, BlockHashBuilder, Epoch, Epochs, FullHash, Link, PrivateKey,
-    PublicKey, QualifiedRoot, Root, Signature,
+    PublicKey, QualifiedRoot, Root, Signature, WorkNonce, WorkVersion,
 };
 use num::FromPrimitive;
 use std::{
@@ -188,6 +191,27 @@ impl Block {
         self.as_block().block_type()
     }
 
+    /// Verifies the signature with the account stored in the block.
+    /// Returns false if the block doesn't contain the account
+    pub fn signature_valid(&self) -> bool {
+        match self.account_field() {
+            Some(account) => account
+                .as_key()
+                .verify(self.hash().as_bytes(), self.signature())
+                .is_ok(),
+            None => false,
+        }
+    }
+
+    /// Serialized size without the leading block type byte
+    pub fn size_serialized(&self) -> usize {
+        serialized_block_size(self.block_type())
+    }
+
+    pub fn size_with_sideband(&self) -> usize {
+        self.size_serialized() + BlockSideband::serialized_size(self.block_type())
+    }
+
     pub fn as_block_mut(&mut self) -> &mut dyn BlockBase {
         match self {
             Block::LegacySend(b) => b,
@@ -228,6 +252,51 @@ impl Block {
         }
     }
 
+    /// The proof of work version required for this block.
+    /// All block types currently use version 1 work.
+    pub fn work_version(&self) -> WorkVersion {
+        WorkVersion::Work1
+    }
+
+    /// Multi-line summary of the block for debugging.
+    /// Only the fields that exist for the block type are listed.
+    pub fn pretty_print(&self) -> String {
+        let mut lines = vec![
+            format!("type:           {:?}", self.block_type()),
+            format!("hash:           {}", self.hash().encode_hex()),
+        ];
+        if let Some(account) = self.account_field() {
+            lines.push(format!("account:        {}", account.encode_account()));
+        }
+        if !matches!(self, Block::LegacyOpen(_)) {
+            lines.push(format!("previous:       {}", self.previous().encode_hex()));
+        }
+        if let Some(balance) = self.balance_field() {
+            lines.push(format!("balance:        {} raw", balance.to_string_dec()));
+        }
+        if let Some(representative) = self.representative_field() {
+            lines.push(format!(
+                "representative: {}",
+                representative.as_account().encode_account()
+            ));
+        }
+        if let Some(destination) = self.destination_field() {
+            lines.push(format!("destination:    {}", destination.encode_account()));
+        }
+        if let Some(source) = self.source_field() {
+            lines.push(format!("source:         {}", source.encode_hex()));
+        }
+        if let Some(link) = self.link_field() {
+            lines.push(format!("link:           {}", link.encode_hex()));
+        }
+        lines.push(format!("work:           {}", WorkNonce::from(self.work())));
+        lines.push(format!(
+            "signature:      {}...",
+            &self.signature().encode_hex()[..16]
+        ));
+        lines.join("\n")
+    }
+
     pub fn source_or_link(&self) -> BlockHash {
         self.source_field()
             .unwrap_or_else(|| self.link_field().unwrap_or_default().into())
@@ -434,6 +503,19 @@ impl SavedBlock {
         }
     }
 
+    /// Verifies the signature with the account of the block, which is
+    /// taken from the sideband for legacy blocks without an account field
+    pub fn signature_valid(&self) -> bool {
+        let account = self.account();
+        if account.is_zero() {
+            return false;
+        }
+        account
+            .as_key()
+            .verify(self.hash().as_bytes(), self.signature())
+            .is_ok()
+    }
+
     pub fn height(&self) -> u64 {
         self.sideband.height
     }
@@ -462,6 +544,15 @@ impl SavedBlock {
         self.sideband.details.is_epoch
     }
 
+    /// The epoch an account is upgraded to, if this is an epoch block
+    pub fn epoch_upgrade_target(&self) -> Option<Epoch> {
+        if self.is_epoch() && matches!(self.block, Block::State(_)) {
+            Some(self.epoch())
+        } else {
+            None
+        }
+    }
+
     pub fn is_receive(&self) -> bool {
         self.sideband.details.is_receive
     }
@@ -684,6 +775,19 @@ impl DependentBlocks {
             .iter()
             .flat_map(|i| if i.is_zero() { None } else { Some(i) })
     }
+
+    pub fn contains(&self, hash: &BlockHash) -> bool {
+        !hash.is_zero() && self.dependents.contains(hash)
+    }
+
+    /// Number of non-zero dependents
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 #[cfg(test)]
@@ -720,6 +824,135 @@ mod tests {
         assert_serializable(block);
     }
 
+    #[test]
+    fn size_serialized() {
+        let blocks: [Block; 5] = [
+            TestBlockBuilder::legacy_open().build(),
+            TestBlockBuilder::legacy_receive().build(),
+            TestBlockBuilder::legacy_send().build(),
+            TestBlockBuilder::legacy_change().build(),
+            TestBlockBuilder::state().build(),
+        ];
+        for block in blocks {
+            let block_type = block.block_type();
+            assert_eq!(block.size_serialized(), serialized_block_size(block_type));
+            assert_eq!(
+                block.size_with_sideband(),
+                serialized_block_size(block_type) + BlockSideband::serialized_size(block_type)
+            );
+
+            let mut buffer = MemoryStream::new();
+            block.serialize_without_block_type(&mut buffer);
+            assert_eq!(buffer.bytes_written(), block.size_serialized());
+        }
+    }
+
+    #[test]
+    fn signature_valid() {
+        let block = Block::new_test_instance();
+        assert!(block.signature_valid());
+    }
+
+    #[test]
+    fn tampered_signature() {
+        let mut block = Block::new_test_instance();
+        block.set_signature(Signature::from_bytes([1; 64]));
+        assert_eq!(block.signature_valid(), false);
+    }
+
+    #[test]
+    fn signature_without_account() {
+        let block = TestBlockBuilder::legacy_send().build();
+        assert_eq!(block.signature_valid(), false);
+    }
+
+    #[test]
+    fn signature_valid_with_sideband_account() {
+        let key = PrivateKey::from(42);
+        let block = TestBlockBuilder::legacy_send().sign(key.clone()).build();
+        let sideband = BlockSideband {
+            account: key.account(),
+            ..BlockSideband::new_test_instance()
+        };
+        let saved = SavedBlock::new(block, sideband);
+        assert!(saved.signature_valid());
+    }
+
+    #[test]
+    fn epoch_upgrade_target() {
+        let block = TestBlockBuilder::state().build();
+        let sideband = BlockSideband {
+            details: BlockDetails::new(Epoch::Epoch2, false, false, true),
+            ..BlockSideband::new_test_instance()
+        };
+        let epoch_block = SavedBlock::new(block, sideband);
+        assert_eq!(epoch_block.epoch_upgrade_target(), Some(Epoch::Epoch2));
+    }
+
+    #[test]
+    fn no_epoch_upgrade_target_for_send() {
+        let send = TestBlockBuilder::legacy_send().build_saved();
+        assert_eq!(send.epoch_upgrade_target(), None);
+    }
+
+    #[test]
+    fn work_version() {
+        let blocks: [Block; 5] = [
+            TestBlockBuilder::legacy_open().build(),
+            TestBlockBuilder::legacy_receive().build(),
+            TestBlockBuilder::legacy_send().build(),
+            TestBlockBuilder::legacy_change().build(),
+            TestBlockBuilder::state().build(),
+        ];
+        for block in blocks {
+            assert_eq!(block.work_version(), WorkVersion::Work1);
+        }
+    }
+
+    #[test]
+    fn pretty_print_state_block() {
+        let block = TestBlockBuilder::state().build();
+        let output = block.pretty_print();
+        assert!(output.contains(&block.hash().encode_hex()));
+        assert!(output.contains(&block.account_field().unwrap().encode_account()));
+        assert!(output.contains("link:"));
+    }
+
+    #[test]
+    fn pretty_print_legacy_open() {
+        let block = TestBlockBuilder::legacy_open().build();
+        let output = block.pretty_print();
+        assert!(output.contains(&block.hash().encode_hex()));
+        assert!(output.contains("source:"));
+        assert_eq!(output.contains("previous:"), false);
+    }
+
+    #[test]
+    fn no_dependents() {
+        let dependents = DependentBlocks::none();
+        assert_eq!(dependents.len(), 0);
+        assert!(dependents.is_empty());
+        assert_eq!(dependents.contains(&BlockHash::zero()), false);
+    }
+
+    #[test]
+    fn one_dependent() {
+        let dependents = DependentBlocks::new(BlockHash::from(1), BlockHash::zero());
+        assert_eq!(dependents.len(), 1);
+        assert!(dependents.contains(&BlockHash::from(1)));
+        assert_eq!(dependents.contains(&BlockHash::from(2)), false);
+        assert_eq!(dependents.contains(&BlockHash::zero()), false);
+    }
+
+    #[test]
+    fn two_dependents() {
+        let dependents = DependentBlocks::new(BlockHash::from(1), BlockHash::from(2));
+        assert_eq!(dependents.len(), 2);
+        assert!(dependents.contains(&BlockHash::from(1)));
+        assert!(dependents.contains(&BlockHash::from(2)));
+        assert_eq!(dependents.contains(&BlockHash::from(3)), false);
+    }
+
     fn assert_serializable(block: Block) {
         let mut buffer = MemoryStream::new();
         block.serialize(&mut buffer);
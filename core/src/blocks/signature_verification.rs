@@ -0,0 +1,34 @@
+use super::Block;
+
+/// Verifies the signatures of many blocks at once.
+/// Blocks which don't contain their signer (legacy send, receive and
+/// change blocks) are reported as invalid.
+pub fn verify_block_signatures_batch(blocks: &[Block]) -> Vec<bool> {
+    blocks.iter().map(|block| block.signature_valid()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PrivateKey, Signature, TestBlockBuilder};
+
+    #[test]
+    fn empty() {
+        assert_eq!(verify_block_signatures_batch(&[]), Vec::<bool>::new());
+    }
+
+    #[test]
+    fn mixed_blocks() {
+        let valid1 = Block::new_test_instance_with_key(PrivateKey::from(1));
+        let valid2 = Block::new_test_instance_with_key(PrivateKey::from(2));
+        let mut invalid = Block::new_test_instance_with_key(PrivateKey::from(1));
+        invalid.set_signature(Signature::from_bytes([1; 64]));
+        let unknown_signer = TestBlockBuilder::legacy_send()
+            .sign(PrivateKey::from(3))
+            .build();
+
+        let results = verify_block_signatures_batch(&[valid1, invalid, unknown_signer, valid2]);
+
+        assert_eq!(results, vec![true, false, false, true]);
+    }
+}
@@ -6,7 +6,7 @@ use blake2::{
 use std::collections::HashMap;
 use std::mem::size_of;
 
-#[derive(Clone, Copy, FromPrimitive, PartialEq, Eq)]
+#[derive(Clone, Copy, FromPrimitive, PartialEq, Eq, Debug)]
 pub enum WorkVersion {
     Unspecified,
     Work1,
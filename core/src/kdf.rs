@@ -13,6 +13,10 @@ impl KeyDerivationFunction {
     }
 
     pub fn hash_password(&self, password: &str, salt: &[u8; 32]) -> RawKey {
+        self.try_hash_password(password, salt).unwrap()
+    }
+
+    pub fn try_hash_password(&self, password: &str, salt: &[u8; 32]) -> anyhow::Result<RawKey> {
         let config = argon2::Config {
             hash_length: 32,
             lanes: 1,
@@ -23,7 +27,7 @@ impl KeyDerivationFunction {
             ..Default::default()
         };
 
-        let hash = argon2::hash_raw(password.as_bytes(), salt, &config).unwrap();
-        RawKey::from_bytes(hash.as_slice().try_into().unwrap())
+        let hash = argon2::hash_raw(password.as_bytes(), salt, &config)?;
+        Ok(RawKey::from_bytes(hash.as_slice().try_into()?))
     }
 }
@@ -0,0 +1,65 @@
+use rsnano_core::{Account, BlockHash, SavedBlock};
+use rsnano_store_lmdb::{LmdbStore, Transaction};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WalkDirection {
+    /// From the frontier down to the open block
+    Backward,
+    /// From the open block up to the frontier
+    Forward,
+}
+
+/// Iterates over the blocks of an account chain.
+/// The walk ends at the end of the chain or at the first block that
+/// isn't in the ledger anymore, for example because it was pruned.
+pub struct AccountChainWalker<'a> {
+    store: &'a LmdbStore,
+    txn: &'a dyn Transaction,
+    direction: WalkDirection,
+    next: BlockHash,
+}
+
+impl<'a> AccountChainWalker<'a> {
+    pub fn new(
+        store: &'a LmdbStore,
+        txn: &'a dyn Transaction,
+        account: &Account,
+        direction: WalkDirection,
+    ) -> Self {
+        let next = match store.account.get(txn, account) {
+            Some(info) => match direction {
+                WalkDirection::Backward => info.head,
+                WalkDirection::Forward => info.open_block,
+            },
+            None => BlockHash::zero(),
+        };
+
+        Self {
+            store,
+            txn,
+            direction,
+            next,
+        }
+    }
+}
+
+impl Iterator for AccountChainWalker<'_> {
+    type Item = SavedBlock;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next.is_zero() {
+            return None;
+        }
+
+        let Some(block) = self.store.block.get(self.txn, &self.next) else {
+            self.next = BlockHash::zero();
+            return None;
+        };
+
+        self.next = match self.direction {
+            WalkDirection::Backward => block.previous(),
+            WalkDirection::Forward => block.successor().unwrap_or_default(),
+        };
+        Some(block)
+    }
+}
@@ -0,0 +1,20 @@
+use super::RollbackError;
+use crate::Ledger;
+use rsnano_core::{BlockHash, SavedBlock};
+use rsnano_store_lmdb::Transaction;
+
+/// Loads a block that is required for the rollback.
+/// Fails with `RollbackError::PrunedDependency` if the block was pruned
+pub(super) fn load_block(
+    ledger: &Ledger,
+    txn: &dyn Transaction,
+    block_hash: &BlockHash,
+) -> anyhow::Result<SavedBlock> {
+    match ledger.any().get_block(txn, block_hash) {
+        Some(block) => Ok(block),
+        None if ledger.store.pruned.exists(txn, block_hash) => {
+            Err(RollbackError::PrunedDependency(*block_hash).into())
+        }
+        None => Err(anyhow!("block not found")),
+    }
+}
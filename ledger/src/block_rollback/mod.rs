@@ -1,8 +1,11 @@
+mod block_loader;
 mod instructions_executor;
 mod planner_factory;
+mod rollback_error;
 mod rollback_performer;
 mod rollback_planner;
 #[cfg(test)]
 mod tests;
 
+pub use rollback_error::RollbackError;
 pub(crate) use rollback_performer::BlockRollbackPerformer;
@@ -1,4 +1,4 @@
-use super::rollback_planner::RollbackPlanner;
+use super::{block_loader::load_block, rollback_planner::RollbackPlanner};
 use crate::Ledger;
 use rsnano_core::{
     utils::seconds_since_epoch, Account, AccountInfo, Block, BlockHash, ConfirmationHeightInfo,
@@ -97,10 +97,7 @@ impl<'a> RollbackPlannerFactory<'a> {
     }
 
     fn load_block(&self, block_hash: &BlockHash) -> anyhow::Result<SavedBlock> {
-        self.ledger
-            .any()
-            .get_block(self.txn, block_hash)
-            .ok_or_else(|| anyhow!("block not found"))
+        load_block(self.ledger, self.txn, block_hash)
     }
 
     fn get_previous_representative(&self) -> anyhow::Result<Option<PublicKey>> {
@@ -0,0 +1,24 @@
+use rsnano_core::BlockHash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollbackError {
+    /// A block that is required for the rollback was pruned from the ledger
+    PrunedDependency(BlockHash),
+    /// The block is at or below the confirmation height of its account
+    BlockConfirmed(BlockHash),
+}
+
+impl std::fmt::Display for RollbackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RollbackError::PrunedDependency(hash) => {
+                write!(f, "rollback depends on pruned block {}", hash)
+            }
+            RollbackError::BlockConfirmed(hash) => {
+                write!(f, "block {} is confirmed and cannot be rolled back", hash)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RollbackError {}
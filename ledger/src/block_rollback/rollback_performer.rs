@@ -4,8 +4,8 @@ use rsnano_store_lmdb::LmdbWriteTransaction;
 use crate::Ledger;
 
 use super::{
-    instructions_executor::RollbackInstructionsExecutor, planner_factory::RollbackPlannerFactory,
-    rollback_planner::RollbackStep,
+    block_loader::load_block, instructions_executor::RollbackInstructionsExecutor,
+    planner_factory::RollbackPlannerFactory, rollback_planner::RollbackStep, RollbackError,
 };
 
 pub(crate) struct BlockRollbackPerformer<'a> {
@@ -24,6 +24,7 @@ impl<'a> BlockRollbackPerformer<'a> {
     }
 
     pub(crate) fn roll_back(mut self, block_hash: &BlockHash) -> anyhow::Result<Vec<SavedBlock>> {
+        self.ensure_block_is_not_confirmed(block_hash)?;
         self.roll_back_block_and_successors(block_hash)?;
         Ok(self.rolled_back)
     }
@@ -58,6 +59,21 @@ impl<'a> BlockRollbackPerformer<'a> {
         }
     }
 
+    fn ensure_block_is_not_confirmed(&self, block_hash: &BlockHash) -> anyhow::Result<()> {
+        let block = self.load_block(block_hash)?;
+        let confirmation_height = self
+            .ledger
+            .store
+            .confirmation_height
+            .get(self.txn, &block.account())
+            .unwrap_or_default();
+
+        if block.height() <= confirmation_height.height {
+            return Err(RollbackError::BlockConfirmed(*block_hash).into());
+        }
+        Ok(())
+    }
+
     fn block_exists(&self, block_hash: &BlockHash) -> bool {
         self.ledger.any().block_exists(self.txn, block_hash)
     }
@@ -74,9 +90,6 @@ impl<'a> BlockRollbackPerformer<'a> {
     }
 
     fn load_block(&self, block_hash: &BlockHash) -> anyhow::Result<SavedBlock> {
-        self.ledger
-            .any()
-            .get_block(self.txn, block_hash)
-            .ok_or_else(|| anyhow!("block not found"))
+        load_block(self.ledger, self.txn, block_hash)
     }
 }
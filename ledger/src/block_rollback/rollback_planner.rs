@@ -1,3 +1,4 @@
+use super::RollbackError;
 use rsnano_core::{
     Account, AccountInfo, Amount, BlockHash, BlockSubType, ConfirmationHeightInfo, Epoch, Epochs,
     PendingInfo, PendingKey, PublicKey, SavedBlock,
@@ -67,7 +68,7 @@ impl<'a> RollbackPlanner<'a> {
 
     fn ensure_block_is_not_confirmed(&self) -> anyhow::Result<()> {
         if self.head_block.height() <= self.confirmation_height.height {
-            bail!("Only unconfirmed blocks can be rolled back")
+            return Err(RollbackError::BlockConfirmed(self.head_block.hash()).into());
         }
 
         Ok(())
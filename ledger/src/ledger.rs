@@ -3,8 +3,9 @@ use crate::{
     block_cementer::BlockCementer,
     block_insertion::{BlockInserter, BlockValidatorFactory},
     ledger_set_confirmed::LedgerSetConfirmed,
-    BlockRollbackPerformer, GenerateCacheFlags, LedgerConstants, LedgerSetAny, RepWeightCache,
-    RepWeightsUpdater, RepresentativeBlockFinder, WriteGuard, WriteQueue,
+    AccountChainWalker, BlockRollbackPerformer, GenerateCacheFlags, LedgerConstants, LedgerSetAny,
+    RepWeightCache, RepWeightsUpdater, RepresentativeBlockFinder, WalkDirection, WriteGuard,
+    WriteQueue,
 };
 use rand::{thread_rng, Rng};
 use rsnano_core::{
@@ -496,6 +497,17 @@ impl Ledger {
         None
     }
 
+    /// Iterates over the blocks of an account chain in the given direction.
+    /// The walk ends early if it reaches a pruned block.
+    pub fn walk_chain<'a>(
+        &'a self,
+        txn: &'a dyn Transaction,
+        account: &Account,
+        direction: WalkDirection,
+    ) -> AccountChainWalker<'a> {
+        AccountChainWalker::new(&self.store, txn, account, direction)
+    }
+
     pub fn epoch_link(&self, epoch: Epoch) -> Option<Link> {
         self.constants.epochs.link(epoch).cloned()
     }
@@ -18,6 +18,7 @@ mod rollback_legacy_change;
 mod rollback_legacy_receive;
 mod rollback_legacy_send;
 mod rollback_state;
+mod walk_chain;
 
 #[test]
 fn ledger_successor() {
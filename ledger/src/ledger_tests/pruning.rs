@@ -1,6 +1,6 @@
 use crate::ledger_tests::helpers::upgrade_genesis_to_epoch_v1;
 use crate::ledger_tests::LedgerContext;
-use crate::{ledger_constants::LEDGER_CONSTANTS_STUB, DEV_GENESIS_HASH};
+use crate::{ledger_constants::LEDGER_CONSTANTS_STUB, RollbackError, DEV_GENESIS_HASH};
 use rsnano_core::{
     work::{WorkPool, STUB_WORK_POOL},
     Amount, Epoch, PendingKey, TestBlockBuilder,
@@ -196,6 +196,36 @@ fn pruning_source_rollback() {
     assert_eq!(ctx.ledger.block_count(), 5);
 }
 
+#[test]
+fn rollback_with_pruned_dependency() {
+    let ctx = LedgerContext::empty();
+    ctx.ledger.enable_pruning();
+    let genesis = ctx.genesis_block_factory();
+    let mut txn = ctx.ledger.rw_txn();
+
+    let send1 = genesis.send(&txn).build();
+    ctx.ledger.process(&mut txn, &send1).unwrap();
+    ctx.ledger.confirm(&mut txn, send1.hash());
+
+    let send2 = genesis.send(&txn).build();
+    ctx.ledger.process(&mut txn, &send2).unwrap();
+
+    assert_eq!(ctx.ledger.pruning_action(&mut txn, &send1.hash(), 1), 1);
+
+    let error = ctx.ledger.rollback(&mut txn, &send2.hash()).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<RollbackError>(),
+        Some(&RollbackError::PrunedDependency(send1.hash()))
+    );
+    assert!(ctx.ledger.store.block.exists(&txn, &send2.hash()));
+
+    let error = ctx.ledger.rollback(&mut txn, &send1.hash()).unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<RollbackError>(),
+        Some(&RollbackError::PrunedDependency(send1.hash()))
+    );
+}
+
 #[test]
 fn pruning_source_rollback_legacy() {
     let ctx = LedgerContext::empty();
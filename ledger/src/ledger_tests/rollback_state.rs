@@ -2,7 +2,7 @@ use super::LedgerContext;
 use crate::{
     ledger_constants::{DEV_GENESIS_PUB_KEY, LEDGER_CONSTANTS_STUB},
     ledger_tests::AccountBlockFactory,
-    DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH,
+    RollbackError, DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH,
 };
 use rsnano_core::{Amount, Epoch, PendingInfo, PendingKey, PublicKey};
 
@@ -38,6 +38,28 @@ fn rollback_send() {
     );
 }
 
+#[test]
+fn rollback_confirmed_block_is_refused() {
+    let ctx = LedgerContext::empty();
+    let mut txn = ctx.ledger.rw_txn();
+    let genesis = ctx.genesis_block_factory();
+
+    let send1 = genesis.send(&txn).build();
+    ctx.ledger.process(&mut txn, &send1).unwrap();
+    ctx.ledger.confirm(&mut txn, send1.hash());
+    let send2 = genesis.send(&txn).build();
+    ctx.ledger.process(&mut txn, &send2).unwrap();
+
+    let error = ctx.ledger.rollback(&mut txn, &send1.hash()).unwrap_err();
+
+    assert_eq!(
+        error.downcast_ref::<RollbackError>(),
+        Some(&RollbackError::BlockConfirmed(send1.hash()))
+    );
+    assert!(ctx.ledger.store.block.exists(&txn, &send1.hash()));
+    assert!(ctx.ledger.store.block.exists(&txn, &send2.hash()));
+}
+
 #[test]
 fn rollback_receive() {
     let ctx = LedgerContext::empty();
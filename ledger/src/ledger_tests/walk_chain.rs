@@ -0,0 +1,83 @@
+use crate::{ledger_tests::LedgerContext, WalkDirection, DEV_GENESIS_HASH};
+use rsnano_core::{Account, BlockHash};
+
+#[test]
+fn walk_backward() {
+    let ctx = LedgerContext::empty();
+    let mut txn = ctx.ledger.rw_txn();
+    let genesis = ctx.genesis_block_factory();
+
+    let send1 = genesis.send(&txn).link(Account::from(1)).build();
+    ctx.ledger.process(&mut txn, &send1).unwrap();
+    let send2 = genesis.send(&txn).link(Account::from(2)).build();
+    ctx.ledger.process(&mut txn, &send2).unwrap();
+
+    let hashes: Vec<BlockHash> = ctx
+        .ledger
+        .walk_chain(&txn, &genesis.account(), WalkDirection::Backward)
+        .map(|b| b.hash())
+        .collect();
+
+    assert_eq!(hashes, vec![send2.hash(), send1.hash(), *DEV_GENESIS_HASH]);
+}
+
+#[test]
+fn walk_forward() {
+    let ctx = LedgerContext::empty();
+    let mut txn = ctx.ledger.rw_txn();
+    let genesis = ctx.genesis_block_factory();
+
+    let send1 = genesis.send(&txn).link(Account::from(1)).build();
+    ctx.ledger.process(&mut txn, &send1).unwrap();
+    let send2 = genesis.send(&txn).link(Account::from(2)).build();
+    ctx.ledger.process(&mut txn, &send2).unwrap();
+
+    let hashes: Vec<BlockHash> = ctx
+        .ledger
+        .walk_chain(&txn, &genesis.account(), WalkDirection::Forward)
+        .map(|b| b.hash())
+        .collect();
+
+    assert_eq!(hashes, vec![*DEV_GENESIS_HASH, send1.hash(), send2.hash()]);
+}
+
+#[test]
+fn walk_unknown_account() {
+    let ctx = LedgerContext::empty();
+    let txn = ctx.ledger.read_txn();
+
+    let mut walker = ctx
+        .ledger
+        .walk_chain(&txn, &Account::from(42), WalkDirection::Backward);
+
+    assert!(walker.next().is_none());
+}
+
+#[test]
+fn walk_stops_at_pruned_block() {
+    let ctx = LedgerContext::empty();
+    ctx.ledger.enable_pruning();
+    let mut txn = ctx.ledger.rw_txn();
+    let genesis = ctx.genesis_block_factory();
+
+    let send1 = genesis.send(&txn).link(Account::from(1)).build();
+    ctx.ledger.process(&mut txn, &send1).unwrap();
+    let send2 = genesis.send(&txn).link(Account::from(2)).build();
+    ctx.ledger.process(&mut txn, &send2).unwrap();
+    ctx.ledger.confirm(&mut txn, send2.hash());
+    assert_eq!(ctx.ledger.pruning_action(&mut txn, &send1.hash(), 1), 1);
+
+    let backward: Vec<BlockHash> = ctx
+        .ledger
+        .walk_chain(&txn, &genesis.account(), WalkDirection::Backward)
+        .map(|b| b.hash())
+        .collect();
+    let forward: Vec<BlockHash> = ctx
+        .ledger
+        .walk_chain(&txn, &genesis.account(), WalkDirection::Forward)
+        .map(|b| b.hash())
+        .collect();
+
+    assert_eq!(backward, vec![send2.hash()]);
+    assert_eq!(forward, vec![*DEV_GENESIS_HASH]);
+}
@@ -4,6 +4,7 @@ extern crate anyhow;
 #[macro_use]
 extern crate num_derive;
 
+mod account_chain_walker;
 mod block_cementer;
 mod block_insertion;
 mod block_rollback;
@@ -22,7 +23,9 @@ mod write_queue;
 #[cfg(test)]
 mod ledger_tests;
 
+pub use account_chain_walker::{AccountChainWalker, WalkDirection};
 pub(crate) use block_rollback::BlockRollbackPerformer;
+pub use block_rollback::RollbackError;
 pub use dependent_blocks_finder::*;
 pub use generate_cache_flags::GenerateCacheFlags;
 pub use ledger::*;
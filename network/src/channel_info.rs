@@ -32,6 +32,7 @@ pub struct ChannelInfo {
     /// the timestamp (in seconds since epoch) of the last time there was successful activity on the socket
     last_activity: AtomicI64,
     last_bootstrap_attempt: AtomicI64,
+    created: Timestamp,
 
     /// Duration in seconds of inactivity that causes a socket timeout
     /// activity is any successful connect, send or receive event
@@ -66,6 +67,7 @@ impl ChannelInfo {
             direction,
             last_activity: AtomicI64::new(now.into()),
             last_bootstrap_attempt: AtomicI64::new(0),
+            created: now,
             timeout_seconds: AtomicU64::new(DEFAULT_TIMEOUT),
             timed_out: AtomicBool::new(false),
             socket_type: AtomicU8::new(ChannelMode::Undefined as u8),
@@ -155,6 +157,10 @@ impl ChannelInfo {
         self.last_activity.load(Ordering::Relaxed).into()
     }
 
+    pub fn created(&self) -> Timestamp {
+        self.created
+    }
+
     pub fn set_last_activity(&self, now: Timestamp) {
         self.last_activity.store(now.into(), Ordering::Relaxed);
     }
@@ -1,9 +1,10 @@
-use crate::{ChannelId, NetworkInfo};
+use crate::{ChannelId, CloseReason, NetworkInfo, NetworkObserver, NullNetworkObserver};
 use rsnano_nullable_clock::SteadyClock;
 use std::{
     sync::{Arc, RwLock},
     time::Duration,
 };
+use tracing::debug;
 
 pub trait DeadChannelCleanupStep: Send {
     fn clean_up_dead_channels(&self, dead_channel_ids: &[ChannelId]);
@@ -15,6 +16,7 @@ pub struct DeadChannelCleanup {
     network: Arc<RwLock<NetworkInfo>>,
     cleanup_cutoff: Duration,
     cleanup_steps: Vec<Box<dyn DeadChannelCleanupStep>>,
+    observer: Arc<dyn NetworkObserver>,
 }
 
 impl DeadChannelCleanup {
@@ -28,21 +30,35 @@ impl DeadChannelCleanup {
             network,
             cleanup_cutoff,
             cleanup_steps: Vec::new(),
+            observer: Arc::new(NullNetworkObserver::new()),
         }
     }
 
+    pub fn set_observer(&mut self, observer: Arc<dyn NetworkObserver>) {
+        self.observer = observer;
+    }
+
     pub fn add_step(&mut self, step: impl DeadChannelCleanupStep + 'static) {
         self.cleanup_steps.push(Box::new(step));
     }
 
     pub fn clean_up(&self) {
-        let removed_channels = self
+        let report = self
             .network
             .write()
             .unwrap()
             .purge(self.clock.now(), self.cleanup_cutoff);
 
-        let channel_ids: Vec<_> = removed_channels.iter().map(|c| c.channel_id()).collect();
+        let handshake_timeouts = report.count(CloseReason::HandshakeTimeout);
+        if handshake_timeouts > 0 {
+            self.observer.handshakes_timed_out(handshake_timeouts);
+        }
+
+        if report.closed() > 0 {
+            debug!(closed = report.closed(), by_reason = ?report.by_reason, "Purged channels");
+        }
+
+        let channel_ids: Vec<_> = report.removed.iter().map(|c| c.channel_id()).collect();
 
         for step in &self.cleanup_steps {
             step.clean_up_dead_channels(&channel_ids);
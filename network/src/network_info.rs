@@ -5,13 +5,13 @@ use crate::{
     utils::{is_ipv4_mapped, map_address_to_subnetwork, reserved_address},
     ChannelId, ChannelInfo, ChannelMode, TrafficType,
 };
-use rand::{seq::SliceRandom, thread_rng};
+use rand::{seq::SliceRandom, thread_rng, RngCore};
 use rsnano_core::{utils::ContainerInfo, Networks, NodeId};
 use rsnano_nullable_clock::Timestamp;
 use std::{
     collections::HashMap,
     net::{Ipv6Addr, SocketAddrV6},
-    sync::Arc,
+    sync::{Arc, Mutex},
     time::Duration,
 };
 use tracing::{debug, warn};
@@ -33,6 +33,9 @@ pub struct NetworkConfig {
     pub disable_max_peers_per_subnetwork: bool, // For testing only
     pub disable_network: bool,
     pub listening_port: u16,
+
+    /// Channels that didn't complete the handshake within this time are closed
+    pub handshake_timeout: Duration,
 }
 
 impl NetworkConfig {
@@ -61,6 +64,11 @@ impl NetworkConfig {
                 Networks::NanoTestNetwork => 17076,
                 _ => 7075,
             },
+            handshake_timeout: if is_dev {
+                Duration::from_secs(5)
+            } else {
+                Duration::from_secs(10)
+            },
         }
     }
 }
@@ -77,6 +85,33 @@ pub enum NetworkError {
     DuplicateConnection,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CloseReason {
+    /// No traffic within the cutoff period
+    IdleTimeout,
+    OutdatedProtocolVersion,
+    /// The handshake wasn't completed in time
+    HandshakeTimeout,
+    /// The channel was already closed, for example because the socket was disconnected
+    Disconnected,
+}
+
+#[derive(Default)]
+pub struct PurgeReport {
+    pub removed: Vec<Arc<ChannelInfo>>,
+    pub by_reason: HashMap<CloseReason, usize>,
+}
+
+impl PurgeReport {
+    pub fn closed(&self) -> usize {
+        self.removed.len()
+    }
+
+    pub fn count(&self, reason: CloseReason) -> usize {
+        self.by_reason.get(&reason).cloned().unwrap_or_default()
+    }
+}
+
 pub struct NetworkInfo {
     next_channel_id: usize,
     channels: HashMap<ChannelId, Arc<ChannelInfo>>,
@@ -85,6 +120,8 @@ pub struct NetworkInfo {
     attempts: AttemptContainer,
     network_config: NetworkConfig,
     excluded_peers: PeerExclusion,
+    /// Used instead of thread_rng for channel selection, so that tests can be reproducible
+    rng: Mutex<Option<Box<dyn RngCore + Send>>>,
 }
 
 impl NetworkInfo {
@@ -97,6 +134,23 @@ impl NetworkInfo {
             attempts: Default::default(),
             network_config,
             excluded_peers: PeerExclusion::new(),
+            rng: Mutex::new(None),
+        }
+    }
+
+    pub fn set_rng(&mut self, rng: Box<dyn RngCore + Send>) {
+        *self.rng.lock().unwrap() = Some(rng);
+    }
+
+    fn shuffle(&self, channels: &mut Vec<Arc<ChannelInfo>>) {
+        let mut guard = self.rng.lock().unwrap();
+        match guard.as_mut() {
+            Some(rng) => {
+                // The channels come from a HashMap, so they have to be sorted first to get a reproducible order
+                channels.sort_by_key(|c| c.channel_id());
+                channels.shuffle(rng);
+            }
+            None => channels.shuffle(&mut thread_rng()),
         }
     }
 
@@ -203,6 +257,18 @@ impl NetworkInfo {
             .find(|c| c.node_id() == Some(*node_id) && c.is_alive())
     }
 
+    /// Closes all channels of the given node and returns how many were closed
+    pub fn close_channels_by_node_id(&self, node_id: &NodeId) -> usize {
+        let mut closed = 0;
+        for channel in self.channels.values() {
+            if channel.node_id() == Some(*node_id) && channel.is_alive() {
+                channel.close();
+                closed += 1;
+            }
+        }
+        closed
+    }
+
     pub fn find_realtime_channel_by_remote_addr(
         &self,
         endpoint: &SocketAddrV6,
@@ -228,8 +294,7 @@ impl NetworkInfo {
 
     pub fn random_realtime_channels(&self, count: usize, min_version: u8) -> Vec<Arc<ChannelInfo>> {
         let mut channels = self.list_realtime(min_version);
-        let mut rng = thread_rng();
-        channels.shuffle(&mut rng);
+        self.shuffle(&mut channels);
         if count > 0 {
             channels.truncate(count)
         }
@@ -266,8 +331,7 @@ impl NetworkInfo {
 
     pub fn random_list_realtime(&self, count: usize, min_version: u8) -> Vec<Arc<ChannelInfo>> {
         let mut channels = self.list_realtime(min_version);
-        let mut rng = thread_rng();
-        channels.shuffle(&mut rng);
+        self.shuffle(&mut channels);
         if count > 0 {
             channels.truncate(count)
         }
@@ -281,42 +345,85 @@ impl NetworkInfo {
             .collect()
     }
 
-    /// Returns channel IDs of removed channels
-    pub fn purge(&mut self, now: Timestamp, cutoff_period: Duration) -> Vec<Arc<ChannelInfo>> {
-        self.close_idle_channels(now, cutoff_period);
+    /// Closes idle and outdated channels and removes all dead channels
+    pub fn purge(&mut self, now: Timestamp, cutoff_period: Duration) -> PurgeReport {
+        let idle = self.close_idle_channels(now, cutoff_period);
+        let unfinished_handshakes = self.close_unfinished_handshakes(now);
 
         // Check if any tcp channels belonging to old protocol versions which may still be alive due to async operations
-        self.close_old_protocol_versions(self.network_config.min_protocol_version);
+        let outdated = self.close_old_protocol_versions(self.network_config.min_protocol_version);
 
         // Remove channels with dead underlying sockets
-        let purged_channels = self.remove_dead_channels();
+        let removed = self.remove_dead_channels();
+
+        let mut by_reason = HashMap::new();
+        for channel in &removed {
+            let reason = if idle.contains(&channel.channel_id()) {
+                CloseReason::IdleTimeout
+            } else if unfinished_handshakes.contains(&channel.channel_id()) {
+                CloseReason::HandshakeTimeout
+            } else if outdated.contains(&channel.channel_id()) {
+                CloseReason::OutdatedProtocolVersion
+            } else {
+                CloseReason::Disconnected
+            };
+            *by_reason.entry(reason).or_default() += 1;
+        }
 
         // Remove keepalive attempt tracking for attempts older than cutoff
         self.attempts.purge(now, cutoff_period);
-        purged_channels
+        PurgeReport { removed, by_reason }
     }
 
-    fn close_idle_channels(&mut self, now: Timestamp, cutoff_period: Duration) {
+    /// Closes idle channels and returns the ids of the channels that were still alive
+    fn close_idle_channels(&mut self, now: Timestamp, cutoff_period: Duration) -> Vec<ChannelId> {
+        let mut closed = Vec::new();
         for entry in self.channels.values() {
             if now - entry.last_activity() >= cutoff_period {
                 debug!(remote_addr = ?entry.peer_addr(), channel_id = %entry.channel_id(), mode = ?entry.mode(), "Closing idle channel");
+                if entry.is_alive() {
+                    closed.push(entry.channel_id());
+                }
                 entry.close();
             }
         }
+        closed
     }
 
-    fn close_old_protocol_versions(&mut self, min_version: u8) {
+    /// Closes channels that are still in the handshake phase after the handshake timeout
+    fn close_unfinished_handshakes(&mut self, now: Timestamp) -> Vec<ChannelId> {
+        let mut closed = Vec::new();
+        for channel in self.channels.values() {
+            if channel.is_alive()
+                && channel.mode() == ChannelMode::Undefined
+                && now - channel.created() >= self.network_config.handshake_timeout
+            {
+                debug!(channel_id = %channel.channel_id(), peer_addr = ?channel.peer_addr(), "Closing channel because the handshake timed out");
+                closed.push(channel.channel_id());
+                channel.close();
+            }
+        }
+        closed
+    }
+
+    /// Closes channels with an old protocol version and returns the ids of the channels that were still alive
+    fn close_old_protocol_versions(&mut self, min_version: u8) -> Vec<ChannelId> {
+        let mut closed = Vec::new();
         for channel in self.channels.values() {
             if channel.protocol_version() < min_version {
                 debug!(channel_id = %channel.channel_id(), peer_addr = ?channel.peer_addr(), version = channel.protocol_version(), min_version,
                     "Closing channel with old protocol version",
                 );
+                if channel.is_alive() {
+                    closed.push(channel.channel_id());
+                }
                 channel.close();
             }
         }
+        closed
     }
 
-    /// Removes dead channels and returns their channel ids
+    /// Removes dead channels and returns them
     fn remove_dead_channels(&mut self) -> Vec<Arc<ChannelInfo>> {
         let dead_channels: Vec<_> = self
             .channels
@@ -424,6 +531,17 @@ impl NetworkInfo {
             .collect()
     }
 
+    pub fn find_channels_by_ip(&self, ip: &Ipv6Addr) -> Vec<Arc<ChannelInfo>> {
+        let mut result: Vec<_> = self
+            .channels
+            .values()
+            .filter(|c| c.is_alive() && c.peer_addr().ip() == ip)
+            .cloned()
+            .collect();
+        result.sort_by_key(|c| c.peer_addr());
+        result
+    }
+
     pub fn find_channels_by_peering_addr(
         &self,
         peering_addr: &SocketAddrV6,
@@ -574,8 +692,7 @@ impl NetworkInfo {
         let mut peers = self.list_realtime(0);
         // Don't include channels with ephemeral remote ports
         peers.retain(|c| c.peering_addr().is_some());
-        let mut rng = thread_rng();
-        peers.shuffle(&mut rng);
+        self.shuffle(&mut peers);
         peers.truncate(endpoints.len());
 
         let null_endpoint = SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0);
@@ -701,6 +818,7 @@ pub struct ChannelsInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
     use rsnano_core::utils::{NULL_ENDPOINT, TEST_ENDPOINT_1, TEST_ENDPOINT_2, TEST_ENDPOINT_3};
 
     #[test]
@@ -718,6 +836,137 @@ mod tests {
         assert_eq!(network.list_realtime_channels(0).len(), 0);
     }
 
+    #[test]
+    fn close_all_channels_of_a_node() {
+        let mut network = NetworkInfo::new_test_instance();
+        let node_id = NodeId::from(456);
+        for peer in [TEST_ENDPOINT_2, TEST_ENDPOINT_3] {
+            let channel = network
+                .add(
+                    TEST_ENDPOINT_1,
+                    peer,
+                    ChannelDirection::Inbound,
+                    ChannelMode::Realtime,
+                    Timestamp::new_test_instance(),
+                )
+                .unwrap();
+            network.set_node_id(channel.channel_id(), node_id);
+        }
+
+        assert_eq!(network.close_channels_by_node_id(&node_id), 2);
+        assert!(network.find_node_id(&node_id).is_none());
+        assert_eq!(network.close_channels_by_node_id(&node_id), 0);
+    }
+
+    #[test]
+    fn reject_channel_when_subnetwork_limit_reached() {
+        let mut network = NetworkInfo::new(NetworkConfig {
+            max_peers_per_subnetwork: 2,
+            ..NetworkConfig::default_for(Networks::NanoDevNetwork)
+        });
+        let peer = |i| SocketAddrV6::new(Ipv6Addr::new(0x2a01, 1, 0, 0, 0, 0, 0, i), 7075, 0, 0);
+
+        for i in 1..=2 {
+            network
+                .add(
+                    TEST_ENDPOINT_1,
+                    peer(i),
+                    ChannelDirection::Inbound,
+                    ChannelMode::Realtime,
+                    Timestamp::new_test_instance(),
+                )
+                .unwrap();
+        }
+
+        let result = network.add(
+            TEST_ENDPOINT_1,
+            peer(3),
+            ChannelDirection::Inbound,
+            ChannelMode::Realtime,
+            Timestamp::new_test_instance(),
+        );
+        assert!(matches!(
+            result,
+            Err(NetworkError::MaxConnectionsPerSubnetwork)
+        ));
+    }
+
+    #[test]
+    fn seeded_rng_selects_channels_reproducibly() {
+        let create_network = || {
+            let mut network = NetworkInfo::new_test_instance();
+            network.set_rng(Box::new(StdRng::seed_from_u64(42)));
+            for i in 1..=10 {
+                let channel = network
+                    .add(
+                        TEST_ENDPOINT_1,
+                        SocketAddrV6::new(
+                            Ipv6Addr::new(0, 0, 0, 0xffff, 0x10, 0, 1, i),
+                            1000,
+                            0,
+                            0,
+                        ),
+                        ChannelDirection::Inbound,
+                        ChannelMode::Realtime,
+                        Timestamp::new_test_instance(),
+                    )
+                    .unwrap();
+                network
+                    .upgrade_to_realtime_connection(channel.channel_id(), NodeId::from(i as u64))
+                    .unwrap();
+            }
+            network
+        };
+
+        let ids = |network: &NetworkInfo| {
+            network
+                .random_realtime_channels(5, 0)
+                .iter()
+                .map(|c| c.channel_id())
+                .collect::<Vec<_>>()
+        };
+
+        let network_a = create_network();
+        let network_b = create_network();
+        assert_eq!(ids(&network_a).len(), 5);
+        assert_eq!(ids(&network_a), ids(&network_b));
+    }
+
+    #[test]
+    fn find_channels_by_ip() {
+        let mut network = NetworkInfo::new_test_instance();
+        let ip = TEST_ENDPOINT_2.ip();
+        for peer in [
+            SocketAddrV6::new(*ip, 1000, 0, 0),
+            SocketAddrV6::new(*ip, 2000, 0, 0),
+            TEST_ENDPOINT_3,
+        ] {
+            network
+                .add(
+                    TEST_ENDPOINT_1,
+                    peer,
+                    ChannelDirection::Inbound,
+                    ChannelMode::Realtime,
+                    Timestamp::new_test_instance(),
+                )
+                .unwrap();
+        }
+
+        let found: Vec<_> = network
+            .find_channels_by_ip(ip)
+            .iter()
+            .map(|c| c.peer_addr())
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                SocketAddrV6::new(*ip, 1000, 0, 0),
+                SocketAddrV6::new(*ip, 2000, 0, 0)
+            ]
+        );
+    }
+
     #[test]
     fn reserved_ip_is_not_a_peer() {
         let network = NetworkInfo::new_test_instance();
@@ -876,5 +1125,92 @@ mod tests {
             network.purge(now, Duration::from_secs(1));
             assert_eq!(network.len(), 1);
         }
+
+        #[test]
+        fn report_close_reasons() {
+            let mut network = NetworkInfo::new_test_instance();
+            let now = Timestamp::new_test_instance();
+            let mut add_channel = |peer| {
+                network
+                    .add(
+                        TEST_ENDPOINT_1,
+                        peer,
+                        ChannelDirection::Outbound,
+                        ChannelMode::Realtime,
+                        now,
+                    )
+                    .unwrap()
+            };
+            let idle = add_channel(TEST_ENDPOINT_2);
+            let disconnected = add_channel(TEST_ENDPOINT_3);
+            let active = add_channel(SocketAddrV6::new(
+                Ipv6Addr::new(0, 0, 0, 0xffff, 0x10, 0, 0, 4),
+                4444,
+                0,
+                0,
+            ));
+            idle.set_last_activity(now - Duration::from_secs(300));
+            disconnected.close();
+            active.set_last_activity(now);
+
+            let report = network.purge(now, Duration::from_secs(1));
+
+            assert_eq!(report.closed(), 2);
+            assert_eq!(report.count(CloseReason::IdleTimeout), 1);
+            assert_eq!(report.count(CloseReason::Disconnected), 1);
+            assert_eq!(report.count(CloseReason::OutdatedProtocolVersion), 0);
+            assert_eq!(network.len(), 1);
+        }
+
+        #[test]
+        fn purge_unfinished_handshake_after_timeout() {
+            let mut network = NetworkInfo::new_test_instance();
+            let start = Timestamp::new_test_instance();
+            let channel = network
+                .add(
+                    TEST_ENDPOINT_1,
+                    TEST_ENDPOINT_2,
+                    ChannelDirection::Inbound,
+                    ChannelMode::Realtime,
+                    start,
+                )
+                .unwrap();
+            let timeout = network.network_config.handshake_timeout;
+
+            let now = start + timeout - Duration::from_millis(1);
+            channel.set_last_activity(now);
+            let report = network.purge(now, Duration::from_secs(300));
+            assert_eq!(report.closed(), 0);
+
+            let now = start + timeout;
+            channel.set_last_activity(now);
+            let report = network.purge(now, Duration::from_secs(300));
+            assert_eq!(report.count(CloseReason::HandshakeTimeout), 1);
+            assert_eq!(network.len(), 0);
+        }
+
+        #[test]
+        fn dont_purge_realtime_channel_after_handshake_timeout() {
+            let mut network = NetworkInfo::new_test_instance();
+            let start = Timestamp::new_test_instance();
+            let channel = network
+                .add(
+                    TEST_ENDPOINT_1,
+                    TEST_ENDPOINT_2,
+                    ChannelDirection::Inbound,
+                    ChannelMode::Realtime,
+                    start,
+                )
+                .unwrap();
+            network
+                .upgrade_to_realtime_connection(channel.channel_id(), NodeId::from(1))
+                .unwrap();
+
+            let now = start + network.network_config.handshake_timeout;
+            channel.set_last_activity(now);
+            let report = network.purge(now, Duration::from_secs(300));
+
+            assert_eq!(report.closed(), 0);
+        }
     }
 }
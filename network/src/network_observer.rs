@@ -9,6 +9,7 @@ pub trait NetworkObserver: Send + Sync {
     fn read_succeeded(&self, _count: usize) {}
     fn read_failed(&self) {}
     fn channel_timed_out(&self, _channel: &ChannelInfo) {}
+    fn handshakes_timed_out(&self, _count: usize) {}
     fn connection_attempt(&self, _peer: &SocketAddrV6) {}
     fn accepted(&self, _peer: &SocketAddrV6, _direction: ChannelDirection) {}
     fn error(&self, _error: NetworkError, _peer: &SocketAddrV6, _direction: ChannelDirection) {}
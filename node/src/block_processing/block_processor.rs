@@ -261,6 +261,15 @@ impl BlockProcessor {
         self.processor_loop.queue_len(source)
     }
 
+    /// Maximum number of blocks that can be queued for the given source
+    pub fn max_queue_len(&self, source: BlockSource) -> usize {
+        let config = &self.processor_loop.config;
+        match source {
+            BlockSource::Live | BlockSource::LiveOriginator => config.max_peer_queue,
+            _ => config.max_system_queue,
+        }
+    }
+
     pub fn on_block_processed(
         &self,
         observer: Box<dyn Fn(BlockStatus, &BlockProcessorContext) + Send + Sync>,
@@ -14,6 +14,9 @@ pub struct AccountSetsConfig {
     pub priorities_max: usize,
     pub blocking_max: usize,
     pub cooldown: Duration,
+    /// Priorities are halved for every half life that passed since the account was last requested.
+    /// Zero disables the decay
+    pub priority_half_life: Duration,
 }
 
 impl Default for AccountSetsConfig {
@@ -23,6 +26,7 @@ impl Default for AccountSetsConfig {
             priorities_max: 256 * 1024,
             blocking_max: 256 * 1024,
             cooldown: Duration::from_secs(3),
+            priority_half_life: Duration::ZERO,
         }
     }
 }
@@ -56,6 +60,7 @@ impl AccountSets {
     pub const PRIORITY_MAX: Priority = Priority::new(128.0);
     pub const PRIORITY_CUTOFF: Priority = Priority::new(0.15);
     pub const MAX_FAILS: usize = 2;
+    const MAX_DEPENDENCY_ACCOUNT_SCAN: usize = 64;
 
     pub fn new(config: AccountSetsConfig) -> Self {
         Self {
@@ -246,9 +251,17 @@ impl AccountSets {
 
         let cutoff = now - self.config.cooldown;
 
-        self.priorities
-            .next_priority(cutoff, filter)
-            .unwrap_or_default()
+        if self.config.priority_half_life.is_zero() {
+            self.priorities.next_priority(cutoff, filter)
+        } else {
+            self.priorities.next_priority_decayed(
+                cutoff,
+                now,
+                self.config.priority_half_life,
+                filter,
+            )
+        }
+        .unwrap_or_default()
     }
 
     pub fn next_blocking(&self, filter: impl Fn(&BlockHash) -> bool) -> BlockHash {
@@ -259,31 +272,51 @@ impl AccountSets {
         self.blocking.next(filter).unwrap_or_default()
     }
 
+    /// Returns a known dependency account that could not be prioritized yet
+    pub fn next_blocking_account(&self, filter: impl Fn(&Account) -> bool) -> Account {
+        self.unprioritized_dependency_accounts()
+            .take(Self::MAX_DEPENDENCY_ACCOUNT_SCAN)
+            .find(|account| filter(account))
+            .unwrap_or_default()
+    }
+
+    /// Known dependency accounts (> account 0) which are neither blocked nor prioritized
+    fn unprioritized_dependency_accounts(&self) -> impl Iterator<Item = Account> + '_ {
+        let begin = Account::zero().inc().unwrap();
+        let mut previous = None;
+        self.blocking
+            .iter_start_dep_account(begin)
+            .map(|entry| entry.dependency_account)
+            // Entries are grouped by dependency account, so duplicates are adjacent
+            .filter(move |account| previous.replace(*account) != Some(*account))
+            .filter(|account| !self.blocked(account) && !self.prioritized(account))
+    }
+
     /// Sets information about the account chain that contains the block hash
     pub fn sync_dependencies(&mut self) -> (usize, usize) {
         let mut inserted = 0;
         let mut insert_failed = 0;
 
-        // Sample all accounts with a known dependency account (> account 0)
-        let begin = Account::zero().inc().unwrap();
-        for entry in self.blocking.iter_start_dep_account(begin) {
-            if self.priorities.len() >= self.config.priorities_max {
-                break;
-            }
-
-            if !self.blocked(&entry.dependency_account)
-                && !self.prioritized(&entry.dependency_account)
-            {
-                if Self::priority_set_impl(
-                    &entry.dependency_account,
-                    Self::PRIORITY_INITIAL,
-                    &self.blocking,
-                    &mut self.priorities,
-                ) {
-                    inserted += 1;
-                } else {
-                    insert_failed += 1;
-                }
+        // Sample all accounts with a known dependency account
+        let capacity = self
+            .config
+            .priorities_max
+            .saturating_sub(self.priorities.len());
+        let accounts: Vec<_> = self
+            .unprioritized_dependency_accounts()
+            .take(capacity)
+            .collect();
+
+        for account in accounts {
+            if Self::priority_set_impl(
+                &account,
+                Self::PRIORITY_INITIAL,
+                &self.blocking,
+                &mut self.priorities,
+            ) {
+                inserted += 1;
+            } else {
+                insert_failed += 1;
             }
         }
 
@@ -299,6 +332,14 @@ impl AccountSets {
         self.priorities.contains(account)
     }
 
+    /// Blocked accounts and the dependency they are waiting for
+    pub fn blocked_entries(&self) -> Vec<(Account, BlockHash)> {
+        self.blocking
+            .iter()
+            .map(|entry| (entry.original_entry.account, entry.dependency))
+            .collect()
+    }
+
     pub fn priority_len(&self) -> usize {
         self.priorities.len()
     }
@@ -375,6 +416,17 @@ mod tests {
         assert_eq!(sets.priority(&account), Priority::ZERO);
     }
 
+    #[test]
+    fn blocked_entries() {
+        let mut sets = AccountSets::default();
+        let account = Account::from(1);
+        let hash = BlockHash::from(2);
+
+        sets.block(account, hash);
+
+        assert_eq!(sets.blocked_entries(), vec![(account, hash)]);
+    }
+
     #[test]
     fn unblock() {
         let mut sets = AccountSets::default();
@@ -463,4 +515,39 @@ mod tests {
         sets.priority_set(&account, prio);
         assert_eq!(sets.priority(&account), prio);
     }
+
+    #[test]
+    fn priority_decay_prefers_fresher_account() {
+        let mut sets = AccountSets::new(AccountSetsConfig {
+            priority_half_life: Duration::from_secs(10),
+            ..Default::default()
+        });
+        let stale = Account::from(1);
+        let fresh = Account::from(2);
+        let start = Timestamp::new_test_instance();
+        sets.priority_up(&stale);
+        sets.priority_up(&fresh);
+        sets.timestamp_set(&stale, start);
+        sets.timestamp_set(&fresh, start + Duration::from_secs(60));
+
+        let next = sets.next_priority(start + Duration::from_secs(120), |_| true);
+
+        assert_eq!(next, fresh);
+    }
+
+    #[test]
+    fn no_priority_decay_by_default() {
+        let mut sets = AccountSets::default();
+        let first = Account::from(1);
+        let second = Account::from(2);
+        let start = Timestamp::new_test_instance();
+        sets.priority_up(&first);
+        sets.priority_up(&second);
+        sets.timestamp_set(&first, start);
+        sets.timestamp_set(&second, start + Duration::from_secs(60));
+
+        let next = sets.next_priority(start + Duration::from_secs(120), |_| true);
+
+        assert_eq!(next, first);
+    }
 }
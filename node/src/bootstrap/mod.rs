@@ -9,6 +9,7 @@ mod ordered_priorities;
 mod ordered_tags;
 mod peer_scoring;
 mod priority;
+mod tag_id_generator;
 mod throttle;
 
 use self::{
@@ -31,8 +32,9 @@ use frontier_scan::{FrontierScan, FrontierScanConfig};
 use num::clamp;
 use ordered_tags::QuerySource;
 use ordered_tags::QueryType;
+pub use peer_scoring::PeerScore;
 use priority::Priority;
-use rand::{thread_rng, Rng, RngCore};
+use rand::{thread_rng, Rng};
 use rsnano_core::{
     utils::ContainerInfo, Account, AccountInfo, Block, BlockHash, BlockType, Frontier,
     HashOrAccount, SavedBlock,
@@ -52,6 +54,7 @@ use std::{
     thread::JoinHandle,
     time::{Duration, Instant},
 };
+pub use tag_id_generator::TagIdGenerator;
 use tracing::warn;
 
 enum VerifyResult {
@@ -75,6 +78,7 @@ pub struct BootstrapService {
     /// Rate limiter for frontier requests
     frontiers_limiter: RateLimiter,
     clock: Arc<SteadyClock>,
+    tag_ids: TagIdGenerator,
     workers: ThreadPoolImpl,
 }
 
@@ -95,12 +99,15 @@ impl BootstrapService {
         message_publisher: MessagePublisher,
         config: BootstrapConfig,
         clock: Arc<SteadyClock>,
+        tag_ids: TagIdGenerator,
     ) -> Self {
+        define_reply_latency_histogram(&stats, config.request_timeout);
         Self {
             block_processor,
             threads: Mutex::new(None),
             mutex: Arc::new(Mutex::new(BootstrapLogic {
                 stopped: false,
+                paused: false,
                 accounts: AccountSets::new(config.account_sets.clone()),
                 scoring: PeerScoring::new(config.clone()),
                 database_scan: DatabaseScan::new(ledger.clone()),
@@ -113,6 +120,8 @@ impl BootstrapService {
                 throttle: Throttle::new(compute_throttle_size(
                     ledger.account_count(),
                     config.throttle_coefficient,
+                    config.throttle_min,
+                    config.throttle_max,
                 )),
                 sync_dependencies_interval: Instant::now(),
                 config: config.clone(),
@@ -127,6 +136,7 @@ impl BootstrapService {
             ledger,
             message_publisher: Mutex::new(message_publisher),
             clock,
+            tag_ids,
             workers: ThreadPoolImpl::create(1, "Bootstrap work"),
         }
     }
@@ -152,6 +162,21 @@ impl BootstrapService {
         }
     }
 
+    /// Temporarily stops sending bootstrap requests.
+    /// Unlike `stop`, this can be undone by calling `resume`.
+    pub fn pause(&self) {
+        self.mutex.lock().unwrap().paused = true;
+    }
+
+    pub fn resume(&self) {
+        self.mutex.lock().unwrap().paused = false;
+        self.condition.notify_all();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.mutex.lock().unwrap().paused
+    }
+
     fn send(&self, channel_id: ChannelId, request: &Message) {
         self.stats.inc(StatType::Bootstrap, DetailType::Request);
 
@@ -170,6 +195,8 @@ impl BootstrapService {
 
     fn create_asc_pull_request(&self, tag: &AsyncTag) -> Message {
         debug_assert!(tag.source != QuerySource::Invalid);
+        self.stats
+            .inc(StatType::BootstrapRequestSource, (&tag.source).into());
 
         {
             let mut guard = self.mutex.lock().unwrap();
@@ -177,36 +204,9 @@ impl BootstrapService {
             guard.tags.insert(tag.clone());
         }
 
-        let req_type = match tag.query_type {
-            QueryType::BlocksByHash | QueryType::BlocksByAccount => {
-                let start_type = if tag.query_type == QueryType::BlocksByHash {
-                    HashType::Block
-                } else {
-                    HashType::Account
-                };
-
-                AscPullReqType::Blocks(BlocksReqPayload {
-                    start_type,
-                    start: tag.start,
-                    count: tag.count as u8,
-                })
-            }
-            QueryType::AccountInfoByHash => AscPullReqType::AccountInfo(AccountInfoReqPayload {
-                target: tag.start,
-                target_type: HashType::Block, // Query account info by block hash
-            }),
-            QueryType::Invalid => panic!("invalid query type"),
-            QueryType::Frontiers => {
-                AscPullReqType::Frontiers(rsnano_messages::FrontiersReqPayload {
-                    start: tag.start.into(),
-                    count: FrontiersReqPayload::MAX_FRONTIERS,
-                })
-            }
-        };
-
         Message::AscPullReq(AscPullReq {
             id: tag.id,
-            req_type,
+            req_type: asc_pull_req_type(tag),
         })
     }
 
@@ -222,6 +222,10 @@ impl BootstrapService {
         self.mutex.lock().unwrap().scoring.len()
     }
 
+    pub fn peer_scores(&self) -> Vec<PeerScore> {
+        self.mutex.lock().unwrap().scoring.snapshot()
+    }
+
     pub fn prioritized(&self, account: &Account) -> bool {
         self.mutex.lock().unwrap().accounts.prioritized(account)
     }
@@ -230,11 +234,21 @@ impl BootstrapService {
         self.mutex.lock().unwrap().accounts.blocked(account)
     }
 
+    pub fn blocked_entries(&self) -> Vec<(Account, BlockHash)> {
+        self.mutex.lock().unwrap().accounts.blocked_entries()
+    }
+
     /* Waits for a condition to be satisfied with incremental backoff */
     fn wait(&self, mut predicate: impl FnMut(&mut BootstrapLogic) -> bool) {
         let mut guard = self.mutex.lock().unwrap();
         let mut interval = Duration::from_millis(5);
-        while !guard.stopped && !predicate(&mut guard) {
+        let mut was_paused = false;
+        while !guard.stopped && (guard.paused || !predicate(&mut guard)) {
+            // Count each pause once, not every backoff iteration while paused
+            if guard.paused && !was_paused {
+                self.stats.inc(StatType::Bootstrap, DetailType::Paused);
+            }
+            was_paused = guard.paused;
             guard = self
                 .condition
                 .wait_timeout_while(guard, interval, |g| !g.stopped)
@@ -247,8 +261,9 @@ impl BootstrapService {
     /* Ensure there is enough space in blockprocessor for queuing new blocks */
     fn wait_blockprocessor(&self) {
         self.wait(|_| {
+            let capacity = self.block_processor.max_queue_len(BlockSource::Bootstrap);
             self.block_processor.queue_len(BlockSource::Bootstrap)
-                < self.config.block_processor_theshold
+                < self.config.effective_block_processor_threshold(capacity)
         });
     }
 
@@ -295,11 +310,11 @@ impl BootstrapService {
         result
     }
 
-    fn wait_blocking(&self) -> BlockHash {
-        let mut result = BlockHash::zero();
+    fn wait_blocking(&self) -> Option<DependencyTarget> {
+        let mut result = None;
         self.wait(|i| {
             result = i.next_blocking(&self.stats);
-            !result.is_zero()
+            result.is_some()
         });
         result
     }
@@ -309,7 +324,7 @@ impl BootstrapService {
             let tx = self.ledger.read_txn();
             self.ledger.store.account.get(&tx, &account)
         };
-        let id = thread_rng().next_u64();
+        let id = self.tag_ids.next_id();
         let now = self.clock.now();
 
         let request = self.create_blocks_request(id, account, account_info, count, source, now);
@@ -380,6 +395,7 @@ impl BootstrapService {
             id,
             account,
             timestamp: now,
+            deadline: self.request_deadline(now),
             query_type,
             start,
             source,
@@ -393,24 +409,23 @@ impl BootstrapService {
     fn create_account_info_request(
         &self,
         id: u64,
-        hash: BlockHash,
+        target: DependencyTarget,
         source: QuerySource,
         now: Timestamp,
     ) -> Message {
-        let tag = AsyncTag {
-            query_type: QueryType::AccountInfoByHash,
-            source,
-            start: hash.into(),
-            account: Account::zero(),
-            hash,
-            count: 0,
-            id,
-            timestamp: now,
-        };
-
+        let tag = account_info_tag(id, target, source, now, self.request_deadline(now));
         self.create_asc_pull_request(&tag)
     }
 
+    fn request_deadline(&self, now: Timestamp) -> Timestamp {
+        request_deadline(
+            now,
+            self.config.request_timeout,
+            self.config.request_timeout_jitter,
+            &mut thread_rng(),
+        )
+    }
+
     fn run_one_priority(&self) {
         self.wait_blockprocessor();
         let Some(channel_id) = self.wait_channel() else {
@@ -472,15 +487,13 @@ impl BootstrapService {
         let Some(channel_id) = self.wait_channel() else {
             return;
         };
-        let blocking = self.wait_blocking();
-        if blocking.is_zero() {
+        let Some(target) = self.wait_blocking() else {
             return;
-        }
+        };
 
         let now = self.clock.now();
-        let id = thread_rng().next_u64();
-        let request =
-            self.create_account_info_request(id, blocking, QuerySource::Dependencies, now);
+        let id = self.tag_ids.next_id();
+        let request = self.create_account_info_request(id, target, QuerySource::Dependencies, now);
 
         self.send(channel_id, &request);
     }
@@ -514,7 +527,7 @@ impl BootstrapService {
     }
 
     fn request_frontiers(&self, start: Account, channel: ChannelId, source: QuerySource) {
-        let id = thread_rng().next_u64();
+        let id = self.tag_ids.next_id();
         let timestamp = self.clock.now();
         let tag = AsyncTag {
             query_type: QueryType::Frontiers,
@@ -525,6 +538,7 @@ impl BootstrapService {
             count: 0,
             id,
             timestamp,
+            deadline: self.request_deadline(timestamp),
         };
         let message = self.create_asc_pull_request(&tag);
         self.send(channel, &message);
@@ -584,16 +598,7 @@ impl BootstrapService {
 
         self.stats.inc(StatType::Bootstrap, DetailType::Reply);
 
-        let valid = match message.pull_type {
-            AscPullAckType::Blocks(_) => matches!(
-                tag.query_type,
-                QueryType::BlocksByHash | QueryType::BlocksByAccount
-            ),
-            AscPullAckType::AccountInfo(_) => tag.query_type == QueryType::AccountInfoByHash,
-            AscPullAckType::Frontiers(_) => tag.query_type == QueryType::Frontiers,
-        };
-
-        if !valid {
+        if !is_valid_response_type(&message.pull_type, tag.query_type) {
             self.stats
                 .inc(StatType::Bootstrap, DetailType::InvalidResponseType);
             return;
@@ -603,11 +608,13 @@ impl BootstrapService {
         self.stats
             .inc(StatType::BootstrapReply, tag.query_type.into());
 
+        let elapsed = tag.timestamp.elapsed(self.clock.now());
         self.stats.sample(
             Sample::BootstrapTagDuration,
-            tag.timestamp.elapsed(self.clock.now()).as_millis() as i64,
+            elapsed.as_millis() as i64,
             (0, self.config.request_timeout.as_millis() as i64),
         );
+        track_reply_latency(&self.stats, elapsed);
 
         drop(guard);
 
@@ -820,26 +827,34 @@ impl BootstrapService {
             return true;
         }
 
+        // A reply to a query by account has to be about that account
+        if tag.query_type == QueryType::AccountInfoByAccount && response.account != tag.account {
+            return false;
+        }
+
         self.stats
             .inc(StatType::BootstrapProcess, DetailType::AccountInfo);
 
         // Prioritize account containing the dependency
         {
             let mut guard = self.mutex.lock().unwrap();
-            let updated = guard
-                .accounts
-                .dependency_update(&tag.hash, response.account);
-            if updated > 0 {
-                self.stats.add(
-                    StatType::BootstrapAccountSets,
-                    DetailType::DependencyUpdate,
-                    updated as u64,
-                );
-            } else {
-                self.stats.inc(
-                    StatType::BootstrapAccountSets,
-                    DetailType::DependencyUpdateFailed,
-                );
+            // The dependency account is already known when it was queried by account
+            if tag.query_type == QueryType::AccountInfoByHash {
+                let updated = guard
+                    .accounts
+                    .dependency_update(&tag.hash, response.account);
+                if updated > 0 {
+                    self.stats.add(
+                        StatType::BootstrapAccountSets,
+                        DetailType::DependencyUpdate,
+                        updated as u64,
+                    );
+                } else {
+                    self.stats.inc(
+                        StatType::BootstrapAccountSets,
+                        DetailType::DependencyUpdateFailed,
+                    );
+                }
             }
 
             if guard
@@ -1008,6 +1023,8 @@ impl BootstrapExt for Arc<BootstrapService> {
 
 struct BootstrapLogic {
     stopped: bool,
+    /// While paused, the request loops idle until bootstrap is resumed
+    paused: bool,
     accounts: AccountSets,
     scoring: PeerScoring,
     database_scan: DatabaseScan,
@@ -1167,18 +1184,28 @@ impl BootstrapLogic {
     }
 
     /* Waits for next available blocking block */
-    fn next_blocking(&self, stats: &Stats) -> BlockHash {
+    fn next_blocking(&self, stats: &Stats) -> Option<DependencyTarget> {
         let blocking = self
             .accounts
             .next_blocking(|hash| self.count_tags_by_hash(hash, QuerySource::Dependencies) == 0);
 
-        if blocking.is_zero() {
-            return blocking;
-        }
+        let target = if !blocking.is_zero() {
+            DependencyTarget::Block(blocking)
+        } else {
+            let account = self.accounts.next_blocking_account(|account| {
+                self.tags
+                    .count_by_account(account, QuerySource::Dependencies)
+                    == 0
+            });
+            if account.is_zero() {
+                return None;
+            }
+            DependencyTarget::Account(account)
+        };
 
         stats.inc(StatType::BootstrapNext, DetailType::NextBlocking);
 
-        blocking
+        Some(target)
     }
 
     fn cleanup_and_sync(&mut self, account_count: u64, stats: &Stats, now: Timestamp) {
@@ -1189,10 +1216,12 @@ impl BootstrapLogic {
         self.throttle.resize(compute_throttle_size(
             account_count,
             self.config.throttle_coefficient,
+            self.config.throttle_min,
+            self.config.throttle_max,
         ));
 
-        let cutoff = now - self.config.request_timeout;
-        let should_timeout = |tag: &AsyncTag| tag.timestamp < cutoff;
+        // Deadlines are jittered, so a tag may wait behind an older one for at most the jitter band
+        let should_timeout = |tag: &AsyncTag| tag.deadline < now;
 
         while let Some(front) = self.tags.front() {
             if !should_timeout(front) {
@@ -1225,6 +1254,11 @@ impl BootstrapLogic {
             .leaf("tags", self.tags.len(), OrderedTags::ELEMENT_SIZE)
             .leaf("throttle", self.throttle.len(), 0)
             .leaf("throttle_success", self.throttle.successes(), 0)
+            .leaf(
+                "throttle_success_ratio",
+                self.throttle.success_ratio_percent(),
+                0,
+            )
             .node("accounts", self.accounts.container_info())
             .node("database_scan", self.database_scan.container_info())
             .node("frontiers", self.frontiers.container_info())
@@ -1233,14 +1267,49 @@ impl BootstrapLogic {
 }
 
 // Calculates a lookback size based on the size of the ledger where larger ledgers have a larger sample count
-fn compute_throttle_size(account_count: u64, throttle_coefficient: usize) -> usize {
+fn compute_throttle_size(
+    account_count: u64,
+    throttle_coefficient: usize,
+    min_size: usize,
+    max_size: usize,
+) -> usize {
     let target = if account_count > 0 {
         throttle_coefficient * ((account_count as f64).ln() as usize)
     } else {
         0
     };
-    const MIN_SIZE: usize = 16;
-    max(target, MIN_SIZE)
+    clamp(target, min_size, max(min_size, max_size))
+}
+
+/// Logarithmic bins in milliseconds: 0, 1, 2, 4, ... up to the request timeout
+fn reply_latency_intervals(request_timeout: Duration) -> Vec<u64> {
+    let max = request_timeout.as_millis() as u64;
+    let mut intervals = vec![0];
+    let mut start = 1;
+    while start <= max {
+        intervals.push(start);
+        start *= 2;
+    }
+    intervals
+}
+
+fn define_reply_latency_histogram(stats: &Stats, request_timeout: Duration) {
+    stats.define_histogram(
+        StatType::Bootstrap,
+        DetailType::ReplyLatency,
+        Direction::In,
+        &reply_latency_intervals(request_timeout),
+    );
+}
+
+fn track_reply_latency(stats: &Stats, elapsed: Duration) {
+    stats.update_histogram(
+        StatType::Bootstrap,
+        DetailType::ReplyLatency,
+        Direction::In,
+        elapsed.as_millis() as u64,
+        1,
+    );
 }
 
 /// Verifies whether the received response is valid. Returns:
@@ -1274,7 +1343,10 @@ fn verify_response(response: &BlocksAckPayload, tag: &AsyncTag) -> VerifyResult
                 return VerifyResult::Invalid;
             }
         }
-        QueryType::AccountInfoByHash | QueryType::Frontiers | QueryType::Invalid => {
+        QueryType::AccountInfoByHash
+        | QueryType::AccountInfoByAccount
+        | QueryType::Frontiers
+        | QueryType::Invalid => {
             return VerifyResult::Invalid;
         }
     }
@@ -1307,13 +1379,23 @@ pub struct BootstrapConfig {
     pub database_warmup_ratio: usize,
     pub max_pull_count: usize,
     pub request_timeout: Duration,
+    /// Random deviation of the request timeout in percent
+    pub request_timeout_jitter: u8,
     pub throttle_coefficient: usize,
+    /// Lower and upper bound for the number of samples tracked by the throttle
+    pub throttle_min: usize,
+    pub throttle_max: usize,
     pub throttle_wait: Duration,
-    pub block_processor_theshold: usize,
+    pub block_processor_threshold: usize,
+    /// If set, the block processor threshold is this fraction of the block processor's
+    /// bootstrap queue capacity and `block_processor_threshold` is ignored
+    pub block_processor_threshold_ratio: Option<f64>,
     /** Minimum accepted protocol version used when bootstrapping */
     pub min_protocol_version: u8,
     pub max_requests: usize,
     pub optimistic_request_percentage: u8,
+    /// Probability of picking a bootstrap peer at random instead of by its success rate
+    pub channel_exploration_probability: f64,
     pub account_sets: AccountSetsConfig,
     pub frontier_scan: FrontierScanConfig,
 }
@@ -1333,18 +1415,33 @@ impl Default for BootstrapConfig {
             database_warmup_ratio: 10,
             max_pull_count: BlocksAckPayload::MAX_BLOCKS,
             request_timeout: Duration::from_secs(3),
+            request_timeout_jitter: 0,
             throttle_coefficient: 8 * 1024,
+            throttle_min: 16,
+            throttle_max: 128 * 1024,
             throttle_wait: Duration::from_millis(100),
-            block_processor_theshold: 1000,
+            block_processor_threshold: 1000,
+            block_processor_threshold_ratio: None,
             min_protocol_version: 0x14, // TODO don't hard code
             max_requests: 1024,
             optimistic_request_percentage: 75,
+            channel_exploration_probability: 0.1,
             account_sets: Default::default(),
             frontier_scan: Default::default(),
         }
     }
 }
 
+impl BootstrapConfig {
+    /// Number of queued bootstrap blocks at which requesting more blocks is paused
+    pub fn effective_block_processor_threshold(&self, queue_capacity: usize) -> usize {
+        match self.block_processor_threshold_ratio {
+            Some(ratio) => ((queue_capacity as f64 * ratio) as usize).max(1),
+            None => self.block_processor_threshold,
+        }
+    }
+}
+
 impl From<&Message> for QueryType {
     fn from(value: &Message) -> Self {
         if let Message::AscPullReq(req) = value {
@@ -1353,7 +1450,10 @@ impl From<&Message> for QueryType {
                     HashType::Account => QueryType::BlocksByAccount,
                     HashType::Block => QueryType::BlocksByHash,
                 },
-                AscPullReqType::AccountInfo(_) => QueryType::AccountInfoByHash,
+                AscPullReqType::AccountInfo(a) => match a.target_type {
+                    HashType::Account => QueryType::AccountInfoByAccount,
+                    HashType::Block => QueryType::AccountInfoByHash,
+                },
                 AscPullReqType::Frontiers(_) => QueryType::Frontiers,
             }
         } else {
@@ -1362,6 +1462,113 @@ impl From<&Message> for QueryType {
     }
 }
 
+/// What is queried to resolve a dependency of a blocked account
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum DependencyTarget {
+    /// The account of the dependency block is not known yet
+    Block(BlockHash),
+    /// The account containing the dependency is known, but it could not be prioritized yet
+    Account(Account),
+}
+
+fn account_info_tag(
+    id: u64,
+    target: DependencyTarget,
+    source: QuerySource,
+    now: Timestamp,
+    deadline: Timestamp,
+) -> AsyncTag {
+    let (query_type, start, account, hash) = match target {
+        DependencyTarget::Block(hash) => (
+            QueryType::AccountInfoByHash,
+            hash.into(),
+            Account::zero(),
+            hash,
+        ),
+        DependencyTarget::Account(account) => (
+            QueryType::AccountInfoByAccount,
+            account.into(),
+            account,
+            BlockHash::zero(),
+        ),
+    };
+
+    AsyncTag {
+        query_type,
+        source,
+        start,
+        account,
+        hash,
+        count: 0,
+        id,
+        timestamp: now,
+        deadline,
+    }
+}
+
+/// Spreads the request timeout by up to +/- `jitter_percent`, so that requests
+/// which were sent at the same time don't all time out at once
+fn request_deadline(
+    now: Timestamp,
+    timeout: Duration,
+    jitter_percent: u8,
+    rng: &mut impl Rng,
+) -> Timestamp {
+    let timeout_ms = timeout.as_millis() as i64;
+    let max_jitter = timeout_ms * min(jitter_percent, 100) as i64 / 100;
+    let jitter = if max_jitter > 0 {
+        rng.gen_range(-max_jitter..=max_jitter)
+    } else {
+        0
+    };
+    now + Duration::from_millis((timeout_ms + jitter) as u64)
+}
+
+fn asc_pull_req_type(tag: &AsyncTag) -> AscPullReqType {
+    match tag.query_type {
+        QueryType::BlocksByHash | QueryType::BlocksByAccount => {
+            let start_type = if tag.query_type == QueryType::BlocksByHash {
+                HashType::Block
+            } else {
+                HashType::Account
+            };
+
+            AscPullReqType::Blocks(BlocksReqPayload {
+                start_type,
+                start: tag.start,
+                count: tag.count as u8,
+            })
+        }
+        QueryType::AccountInfoByHash => AscPullReqType::AccountInfo(AccountInfoReqPayload {
+            target: tag.start,
+            target_type: HashType::Block, // Query account info by block hash
+        }),
+        QueryType::AccountInfoByAccount => AscPullReqType::AccountInfo(AccountInfoReqPayload {
+            target: tag.start,
+            target_type: HashType::Account,
+        }),
+        QueryType::Invalid => panic!("invalid query type"),
+        QueryType::Frontiers => AscPullReqType::Frontiers(rsnano_messages::FrontiersReqPayload {
+            start: tag.start.into(),
+            count: FrontiersReqPayload::MAX_FRONTIERS,
+        }),
+    }
+}
+
+fn is_valid_response_type(response: &AscPullAckType, query_type: QueryType) -> bool {
+    match response {
+        AscPullAckType::Blocks(_) => matches!(
+            query_type,
+            QueryType::BlocksByHash | QueryType::BlocksByAccount
+        ),
+        AscPullAckType::AccountInfo(_) => matches!(
+            query_type,
+            QueryType::AccountInfoByHash | QueryType::AccountInfoByAccount
+        ),
+        AscPullAckType::Frontiers(_) => query_type == QueryType::Frontiers,
+    }
+}
+
 fn process_frontiers(
     ledger: Arc<Ledger>,
     stats: Arc<Stats>,
@@ -1443,3 +1650,327 @@ fn process_frontiers(
             .priority_set(&account, AccountSets::PRIORITY_CUTOFF);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use rsnano_network::ChannelInfo;
+    use test_helpers::{assert_always_eq, assert_timely};
+
+    #[test]
+    fn query_account_info_by_account() {
+        let account = Account::from(42);
+        let tag = account_info_tag(
+            7,
+            DependencyTarget::Account(account),
+            QuerySource::Dependencies,
+            Timestamp::new_test_instance(),
+            Timestamp::new_test_instance(),
+        );
+
+        let req_type = asc_pull_req_type(&tag);
+
+        assert_eq!(
+            req_type,
+            AscPullReqType::AccountInfo(AccountInfoReqPayload {
+                target: account.into(),
+                target_type: HashType::Account,
+            })
+        );
+        let message = Message::AscPullReq(AscPullReq { id: 7, req_type });
+        assert_eq!(QueryType::from(&message), QueryType::AccountInfoByAccount);
+    }
+
+    #[test]
+    fn query_account_info_by_hash() {
+        let hash = BlockHash::from(42);
+        let tag = account_info_tag(
+            7,
+            DependencyTarget::Block(hash),
+            QuerySource::Dependencies,
+            Timestamp::new_test_instance(),
+            Timestamp::new_test_instance(),
+        );
+
+        assert_eq!(tag.hash, hash);
+        assert_eq!(
+            asc_pull_req_type(&tag),
+            AscPullReqType::AccountInfo(AccountInfoReqPayload {
+                target: hash.into(),
+                target_type: HashType::Block,
+            })
+        );
+    }
+
+    #[test]
+    fn account_info_response_matches_account_info_tags() {
+        let response = AscPullAckType::AccountInfo(AccountInfoAckPayload::default());
+        assert!(is_valid_response_type(
+            &response,
+            QueryType::AccountInfoByAccount
+        ));
+        assert!(is_valid_response_type(
+            &response,
+            QueryType::AccountInfoByHash
+        ));
+        assert!(!is_valid_response_type(
+            &response,
+            QueryType::BlocksByAccount
+        ));
+    }
+
+    #[test]
+    fn request_deadline_without_jitter() {
+        let now = Timestamp::new_test_instance();
+        let deadline = request_deadline(now, Duration::from_secs(3), 0, &mut thread_rng());
+        assert_eq!(deadline, now + Duration::from_secs(3));
+    }
+
+    #[test]
+    fn request_deadline_jitter() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let now = Timestamp::new_test_instance();
+        let timeout = Duration::from_secs(3);
+
+        let deadline1 = request_deadline(now, timeout, 20, &mut rng);
+        let deadline2 = request_deadline(now, timeout, 20, &mut rng);
+
+        assert_ne!(deadline1, deadline2);
+        for deadline in [deadline1, deadline2] {
+            assert!(deadline >= now + Duration::from_millis(2400));
+            assert!(deadline <= now + Duration::from_millis(3600));
+        }
+    }
+
+    #[tokio::test]
+    async fn request_source_stats() {
+        let bootstrap = create_bootstrap_service(TagIdGenerator::new_sequential(1));
+        let channel = ChannelId::from(1);
+
+        bootstrap.request(Account::from(1), 16, channel, QuerySource::Priority);
+        bootstrap.request(Account::from(2), 16, channel, QuerySource::Database);
+        bootstrap.request(Account::from(3), 16, channel, QuerySource::Database);
+        let request = bootstrap.create_account_info_request(
+            4,
+            DependencyTarget::Block(BlockHash::from(4)),
+            QuerySource::Dependencies,
+            bootstrap.clock.now(),
+        );
+        bootstrap.send(channel, &request);
+
+        let count = |source: QuerySource| {
+            bootstrap.stats.count(
+                StatType::BootstrapRequestSource,
+                (&source).into(),
+                Direction::In,
+            )
+        };
+        assert_eq!(count(QuerySource::Priority), 1);
+        assert_eq!(count(QuerySource::Database), 2);
+        assert_eq!(count(QuerySource::Dependencies), 1);
+        assert_eq!(count(QuerySource::Frontiers), 0);
+    }
+
+    #[test]
+    fn absolute_block_processor_threshold() {
+        let config = BootstrapConfig {
+            block_processor_threshold: 1000,
+            ..Default::default()
+        };
+        assert_eq!(config.effective_block_processor_threshold(100), 1000);
+        assert_eq!(config.effective_block_processor_threshold(100_000), 1000);
+    }
+
+    #[test]
+    fn block_processor_threshold_scales_with_capacity() {
+        let config = BootstrapConfig {
+            block_processor_threshold_ratio: Some(0.25),
+            ..Default::default()
+        };
+        assert_eq!(config.effective_block_processor_threshold(4000), 1000);
+        assert_eq!(config.effective_block_processor_threshold(16 * 1024), 4096);
+        assert_eq!(config.effective_block_processor_threshold(0), 1);
+    }
+
+    #[tokio::test]
+    async fn created_tags_use_injected_ids() {
+        let bootstrap = create_bootstrap_service(TagIdGenerator::new_sequential(100));
+
+        bootstrap.request_frontiers(Account::from(1), ChannelId::from(1), QuerySource::Frontiers);
+        bootstrap.request_frontiers(Account::from(2), ChannelId::from(1), QuerySource::Frontiers);
+
+        let guard = bootstrap.mutex.lock().unwrap();
+        assert_eq!(guard.tags.len(), 2);
+        assert!(guard.tags.contains(100));
+        assert!(guard.tags.contains(101));
+    }
+
+    #[tokio::test]
+    async fn pause_and_resume() {
+        let bootstrap = create_bootstrap_service(TagIdGenerator::new_sequential(1));
+        let channel = Arc::new(ChannelInfo::new_test_instance());
+        {
+            let mut guard = bootstrap.mutex.lock().unwrap();
+            guard.scoring.sync(&[channel.clone()]);
+            guard.accounts.priority_set_initial(&Account::from(1));
+        }
+
+        bootstrap.pause();
+        assert!(bootstrap.is_paused());
+
+        std::thread::scope(|s| {
+            let handle = s.spawn(|| bootstrap.run_one_priority());
+            assert_timely(Duration::from_secs(5), || {
+                bootstrap
+                    .stats
+                    .count(StatType::Bootstrap, DetailType::Paused, Direction::In)
+                    > 0
+            });
+            assert_always_eq(
+                Duration::from_millis(200),
+                || {
+                    bootstrap
+                        .stats
+                        .count(StatType::Bootstrap, DetailType::Paused, Direction::In)
+                },
+                1,
+            );
+            assert_eq!(bootstrap.mutex.lock().unwrap().tags.len(), 0);
+
+            bootstrap.resume();
+            handle.join().unwrap();
+        });
+
+        assert_eq!(bootstrap.is_paused(), false);
+        let guard = bootstrap.mutex.lock().unwrap();
+        assert_eq!(guard.tags.len(), 1);
+        assert!(guard.tags.contains(1));
+    }
+
+    #[tokio::test]
+    async fn account_info_reply_for_queried_account() {
+        let bootstrap = create_bootstrap_service(TagIdGenerator::new_sequential(1));
+        let account = Account::from(42);
+        insert_account_info_tag(&bootstrap, 7, account);
+
+        bootstrap.process(account_info_ack(7, account), ChannelId::from(1));
+
+        assert!(bootstrap.prioritized(&account));
+        assert_eq!(
+            bootstrap.stats.count(
+                StatType::Bootstrap,
+                DetailType::InvalidResponse,
+                Direction::In
+            ),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn reject_account_info_reply_for_wrong_account() {
+        let bootstrap = create_bootstrap_service(TagIdGenerator::new_sequential(1));
+        let queried = Account::from(42);
+        let other = Account::from(43);
+        insert_account_info_tag(&bootstrap, 7, queried);
+
+        bootstrap.process(account_info_ack(7, other), ChannelId::from(1));
+
+        assert!(!bootstrap.prioritized(&queried));
+        assert!(!bootstrap.prioritized(&other));
+        assert_eq!(
+            bootstrap.stats.count(
+                StatType::Bootstrap,
+                DetailType::InvalidResponse,
+                Direction::In
+            ),
+            1
+        );
+    }
+
+    fn insert_account_info_tag(bootstrap: &BootstrapService, id: u64, account: Account) {
+        let now = bootstrap.clock.now();
+        let tag = account_info_tag(
+            id,
+            DependencyTarget::Account(account),
+            QuerySource::Dependencies,
+            now,
+            now + Duration::from_secs(5),
+        );
+        bootstrap.mutex.lock().unwrap().tags.insert(tag);
+    }
+
+    fn account_info_ack(id: u64, account: Account) -> AscPullAck {
+        AscPullAck {
+            id,
+            pull_type: AscPullAckType::AccountInfo(AccountInfoAckPayload {
+                account,
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn throttle_size() {
+        let coefficient = 8 * 1024;
+        let min = 16;
+        let max = 128 * 1024;
+        assert_eq!(compute_throttle_size(0, coefficient, min, max), min);
+        assert_eq!(compute_throttle_size(2, coefficient, min, max), min);
+        assert_eq!(
+            compute_throttle_size(1000, coefficient, min, max),
+            6 * coefficient
+        );
+        assert_eq!(compute_throttle_size(u64::MAX, coefficient, min, max), max);
+    }
+
+    #[test]
+    fn reply_latency_intervals_are_logarithmic() {
+        assert_eq!(
+            reply_latency_intervals(Duration::from_millis(10)),
+            [0, 1, 2, 4, 8]
+        );
+    }
+
+    #[test]
+    fn track_reply_latencies() {
+        let stats = Stats::default();
+        define_reply_latency_histogram(&stats, Duration::from_millis(100));
+
+        for elapsed_ms in [0, 3, 3, 50, 5000] {
+            track_reply_latency(&stats, Duration::from_millis(elapsed_ms));
+        }
+
+        let counts: Vec<_> = stats
+            .histogram(StatType::Bootstrap, DetailType::ReplyLatency, Direction::In)
+            .iter()
+            .map(|bin| (bin.start, bin.count))
+            .collect();
+        assert_eq!(
+            counts,
+            [
+                (0, 1),
+                (1, 0),
+                (2, 2),
+                (4, 0),
+                (8, 0),
+                (16, 0),
+                (32, 1),
+                (64, 1)
+            ]
+        );
+    }
+
+    fn create_bootstrap_service(tag_ids: TagIdGenerator) -> BootstrapService {
+        BootstrapService::new(
+            Arc::new(BlockProcessor::new_null()),
+            Arc::new(Ledger::new_null()),
+            Arc::new(Stats::default()),
+            Arc::new(RwLock::new(NetworkInfo::new_test_instance())),
+            MessagePublisher::new_null(tokio::runtime::Handle::current()),
+            BootstrapConfig::default(),
+            Arc::new(SteadyClock::new_null()),
+            tag_ids,
+        )
+    }
+}
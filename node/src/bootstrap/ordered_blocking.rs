@@ -104,6 +104,10 @@ impl OrderedBlocking {
             .map(|acc| self.by_account.get(acc).unwrap())
     }
 
+    pub fn iter(&self) -> impl Iterator<Item = &BlockingEntry> {
+        self.by_account.values()
+    }
+
     pub fn get(&self, account: &Account) -> Option<&BlockingEntry> {
         self.by_account.get(account)
     }
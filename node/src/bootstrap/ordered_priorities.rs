@@ -3,6 +3,7 @@ use rsnano_core::Account;
 use rsnano_nullable_clock::Timestamp;
 use std::collections::BTreeMap;
 use std::mem::size_of;
+use std::time::Duration;
 
 #[derive(Clone, Default)]
 pub(crate) struct PriorityEntry {
@@ -22,6 +23,16 @@ impl PriorityEntry {
         }
     }
 
+    pub fn decayed_priority(&self, now: Timestamp, half_life: Duration) -> Priority {
+        match self.timestamp {
+            Some(ts) if !half_life.is_zero() => {
+                let half_lives = ts.elapsed(now).as_secs_f64() / half_life.as_secs_f64();
+                self.priority * 0.5f64.powf(half_lives)
+            }
+            _ => self.priority,
+        }
+    }
+
     #[allow(dead_code)]
     pub fn new_test_instance() -> Self {
         Self {
@@ -162,6 +173,47 @@ impl OrderedPriorities {
             .map(|e| e.account)
     }
 
+    /// Like `next_priority`, but the priority of each entry is halved for every
+    /// `half_life` that passed since its timestamp
+    pub fn next_priority_decayed(
+        &self,
+        cutoff: Timestamp,
+        now: Timestamp,
+        half_life: Duration,
+        filter: impl Fn(&Account) -> bool,
+    ) -> Option<Account> {
+        let mut best: Option<(Priority, Account)> = None;
+        for entry in self
+            .by_priority
+            .values()
+            .flatten()
+            .map(|account| self.by_account.get(account).unwrap())
+        {
+            if let Some((best_priority, _)) = best {
+                // Decay never increases a priority, so no remaining entry can win
+                if entry.priority <= best_priority {
+                    break;
+                }
+            }
+
+            if let Some(ts) = entry.timestamp {
+                if ts > cutoff {
+                    continue;
+                }
+            }
+
+            if !filter(&entry.account) {
+                continue;
+            }
+
+            let decayed = entry.decayed_priority(now, half_life);
+            if best.map_or(true, |(best_priority, _)| decayed > best_priority) {
+                best = Some((decayed, entry.account));
+            }
+        }
+        best.map(|(_, account)| account)
+    }
+
     pub fn remove(&mut self, account: &Account) -> Option<PriorityEntry> {
         if let Some(entry) = self.by_account.remove(account) {
             self.remove_priority(account, entry.priority);
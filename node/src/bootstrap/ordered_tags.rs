@@ -14,6 +14,7 @@ pub(crate) enum QueryType {
     BlocksByHash,
     BlocksByAccount,
     AccountInfoByHash,
+    AccountInfoByAccount,
     Frontiers,
 }
 
@@ -24,6 +25,7 @@ impl From<QueryType> for DetailType {
             QueryType::BlocksByHash => DetailType::BlocksByHash,
             QueryType::BlocksByAccount => DetailType::BlocksByAccount,
             QueryType::AccountInfoByHash => DetailType::AccountInfoByHash,
+            QueryType::AccountInfoByAccount => DetailType::AccountInfoByAccount,
             QueryType::Frontiers => DetailType::Frontiers,
         }
     }
@@ -39,6 +41,18 @@ pub(crate) enum QuerySource {
     Frontiers,
 }
 
+impl From<&QuerySource> for DetailType {
+    fn from(value: &QuerySource) -> Self {
+        match value {
+            QuerySource::Invalid => DetailType::Invalid,
+            QuerySource::Priority => DetailType::Priority,
+            QuerySource::Database => DetailType::Database,
+            QuerySource::Dependencies => DetailType::Dependencies,
+            QuerySource::Frontiers => DetailType::Frontiers,
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct AsyncTag {
     pub query_type: QueryType,
@@ -49,6 +63,8 @@ pub(crate) struct AsyncTag {
     pub count: usize,
     pub id: u64,
     pub timestamp: Timestamp,
+    /// The request times out when this deadline has passed
+    pub deadline: Timestamp,
 }
 
 #[derive(Default)]
@@ -1,4 +1,5 @@
 use super::BootstrapConfig;
+use rand::{seq::SliceRandom, thread_rng, Rng};
 use rsnano_network::{ChannelId, ChannelInfo, TrafficType};
 use std::{
     collections::{BTreeMap, HashMap},
@@ -29,7 +30,7 @@ impl PeerScoring {
     }
 
     pub fn channel(&mut self) -> Option<Arc<ChannelInfo>> {
-        if let Some(channel) = self.get_next_channel() {
+        if let Some(channel) = self.get_next_channel(&mut thread_rng()) {
             self.scoring.modify(channel.channel_id(), |i| {
                 i.outstanding += 1;
                 i.request_count_total += 1;
@@ -40,23 +41,61 @@ impl PeerScoring {
         }
     }
 
-    fn get_next_channel(&self) -> Option<Arc<ChannelInfo>> {
-        self.scoring.iter_by_outstanding().find_map(|score| {
-            if let Some(channel) = score.channel.upgrade() {
+    /// Picks one of the channels with the fewest outstanding requests, weighted by
+    /// its success rate, so that reliable peers are preferred.
+    /// With a configurable probability any channel is picked uniformly instead, so that
+    /// peers with a bad score still get a chance to improve it.
+    fn get_next_channel(&self, rng: &mut impl Rng) -> Option<Arc<ChannelInfo>> {
+        let candidates: Vec<_> = self
+            .scoring
+            .iter_by_outstanding()
+            .filter_map(|score| {
+                let channel = score.channel.upgrade()?;
                 if !channel.is_queue_full(TrafficType::Generic)
                     && score.outstanding < self.config.channel_limit
                 {
-                    return Some(channel);
+                    Some((channel, score.outstanding, score.success_rate()))
+                } else {
+                    None
                 }
-            }
-            None
-        })
+            })
+            .collect();
+
+        let chosen = if rng.gen_bool(self.exploration_probability()) {
+            candidates.choose(rng)
+        } else {
+            // Candidates are ordered by outstanding requests
+            let min_outstanding = candidates.first()?.1;
+            let least_busy = candidates
+                .iter()
+                .take_while(|(_, outstanding, _)| *outstanding == min_outstanding)
+                .count();
+            candidates[..least_busy]
+                .choose_weighted(rng, |(_, _, rate)| *rate)
+                .ok()
+        };
+        chosen.map(|(channel, _, _)| channel.clone())
+    }
+
+    /// The configured exploration probability, limited to a valid probability
+    fn exploration_probability(&self) -> f64 {
+        let probability = self.config.channel_exploration_probability;
+        if probability.is_nan() {
+            0.0
+        } else {
+            probability.clamp(0.0, 1.0)
+        }
     }
 
     pub fn len(&self) -> usize {
         self.scoring.len()
     }
 
+    /// Current scores of all tracked peers, ordered by outstanding requests
+    pub fn snapshot(&self) -> Vec<PeerScore> {
+        self.scoring.iter_by_outstanding().cloned().collect()
+    }
+
     pub fn timeout(&mut self) {
         self.scoring.retain(|i| i.is_alive());
         self.scoring.modify_all(|i| i.decay());
@@ -75,7 +114,8 @@ impl PeerScoring {
     }
 }
 
-struct PeerScore {
+#[derive(Clone)]
+pub struct PeerScore {
     channel_id: ChannelId,
     channel: Weak<ChannelInfo>,
     /// Number of outstanding requests to a peer
@@ -85,6 +125,35 @@ struct PeerScore {
 }
 
 impl PeerScore {
+    pub fn channel_id(&self) -> ChannelId {
+        self.channel_id
+    }
+
+    /// Number of requests that are still waiting for a response
+    pub fn outstanding(&self) -> usize {
+        self.outstanding
+    }
+
+    pub fn request_count(&self) -> usize {
+        self.request_count_total
+    }
+
+    /// Number of requests that were answered
+    pub fn success_count(&self) -> usize {
+        self.response_count_total
+    }
+
+    /// Number of requests that were neither answered nor are still outstanding
+    pub fn failure_count(&self) -> usize {
+        self.request_count_total
+            .saturating_sub(self.response_count_total + self.outstanding)
+    }
+
+    /// Smoothed ratio of answered requests, so that new peers don't start at zero
+    fn success_rate(&self) -> f64 {
+        (self.response_count_total + 1) as f64 / (self.request_count_total + 1) as f64
+    }
+
     fn new(channel: &Arc<ChannelInfo>) -> Self {
         Self {
             channel_id: channel.channel_id(),
@@ -204,3 +273,137 @@ impl Scoring {
             .map(|id| self.by_channel.get(id).unwrap())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+    use rsnano_core::utils::{TEST_ENDPOINT_1, TEST_ENDPOINT_2};
+    use rsnano_network::ChannelDirection;
+    use rsnano_nullable_clock::Timestamp;
+
+    #[test]
+    fn empty_snapshot() {
+        let scoring = PeerScoring::new(BootstrapConfig::default());
+        assert!(scoring.snapshot().is_empty());
+    }
+
+    #[test]
+    fn snapshot_contains_updated_tally() {
+        let mut scoring = PeerScoring::new(BootstrapConfig::default());
+        let channel = Arc::new(ChannelInfo::new_test_instance());
+        scoring.sync(&[channel.clone()]);
+        scoring.channel().unwrap();
+
+        scoring.received_message(channel.channel_id());
+
+        let snapshot = scoring.snapshot();
+        assert_eq!(snapshot.len(), 1);
+        let score = &snapshot[0];
+        assert_eq!(score.channel_id(), channel.channel_id());
+        assert_eq!(score.outstanding(), 1);
+        assert_eq!(score.request_count(), 2);
+        assert_eq!(score.success_count(), 1);
+        assert_eq!(score.failure_count(), 0);
+    }
+
+    #[test]
+    fn prefer_channels_with_high_success_rate() {
+        let mut scoring = PeerScoring::new(BootstrapConfig {
+            channel_exploration_probability: 0.1,
+            ..Default::default()
+        });
+        let good = Arc::new(ChannelInfo::new_test_instance());
+        let bad = Arc::new(ChannelInfo::new(
+            ChannelId::from(43),
+            TEST_ENDPOINT_1,
+            TEST_ENDPOINT_2,
+            ChannelDirection::Outbound,
+            u8::MAX,
+            Timestamp::new_test_instance(),
+        ));
+        scoring.sync(&[good.clone(), bad.clone()]);
+        scoring.scoring.modify(good.channel_id(), |i| {
+            i.request_count_total = 100;
+            i.response_count_total = 99;
+        });
+        scoring.scoring.modify(bad.channel_id(), |i| {
+            i.request_count_total = 100;
+            i.response_count_total = 0;
+        });
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut good_count = 0;
+        let mut bad_count = 0;
+        for _ in 0..1000 {
+            let channel_id = scoring.get_next_channel(&mut rng).unwrap().channel_id();
+            if channel_id == good.channel_id() {
+                good_count += 1;
+            } else {
+                bad_count += 1;
+            }
+        }
+
+        assert!(good_count > 850, "good channel chosen {} times", good_count);
+        // exploration still picks the failing channel now and then
+        assert!(bad_count > 0);
+    }
+
+    #[test]
+    fn prefer_channels_with_fewest_outstanding_requests() {
+        let mut scoring = PeerScoring::new(BootstrapConfig {
+            channel_exploration_probability: 0.0,
+            ..Default::default()
+        });
+        let idle = Arc::new(ChannelInfo::new_test_instance());
+        let busy = Arc::new(ChannelInfo::new(
+            ChannelId::from(43),
+            TEST_ENDPOINT_1,
+            TEST_ENDPOINT_2,
+            ChannelDirection::Outbound,
+            u8::MAX,
+            Timestamp::new_test_instance(),
+        ));
+        scoring.sync(&[idle.clone(), busy.clone()]);
+        scoring.scoring.modify(busy.channel_id(), |i| {
+            i.outstanding = 3;
+            i.request_count_total = 100;
+            i.response_count_total = 97;
+        });
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let channel = scoring.get_next_channel(&mut rng).unwrap();
+            assert_eq!(channel.channel_id(), idle.channel_id());
+        }
+    }
+
+    #[test]
+    fn invalid_exploration_probability() {
+        for probability in [f64::NAN, -1.0, 2.0] {
+            let mut scoring = PeerScoring::new(BootstrapConfig {
+                channel_exploration_probability: probability,
+                ..Default::default()
+            });
+            let channel = Arc::new(ChannelInfo::new_test_instance());
+            scoring.sync(&[channel.clone()]);
+
+            let mut rng = StdRng::seed_from_u64(42);
+            assert!(scoring.get_next_channel(&mut rng).is_some());
+        }
+    }
+
+    #[test]
+    fn timed_out_requests_count_as_failures() {
+        let mut scoring = PeerScoring::new(BootstrapConfig::default());
+        let channel = Arc::new(ChannelInfo::new_test_instance());
+        scoring.sync(&[channel.clone()]);
+        scoring.channel().unwrap();
+
+        scoring.timeout();
+
+        let score = &scoring.snapshot()[0];
+        assert_eq!(score.outstanding(), 1);
+        assert_eq!(score.failure_count(), 1);
+    }
+}
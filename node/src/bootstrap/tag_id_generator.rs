@@ -0,0 +1,44 @@
+use rand::{thread_rng, RngCore};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Creates the ids of bootstrap requests.
+/// The ids are random by default. Tests can use sequential ids instead,
+/// so that they can assert on specific tags.
+#[derive(Default)]
+pub struct TagIdGenerator {
+    sequence: Option<AtomicU64>,
+}
+
+impl TagIdGenerator {
+    pub fn new_sequential(first_id: u64) -> Self {
+        Self {
+            sequence: Some(AtomicU64::new(first_id)),
+        }
+    }
+
+    pub fn next_id(&self) -> u64 {
+        match &self.sequence {
+            Some(sequence) => sequence.fetch_add(1, Ordering::Relaxed),
+            None => thread_rng().next_u64(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequential() {
+        let generator = TagIdGenerator::new_sequential(100);
+        assert_eq!(generator.next_id(), 100);
+        assert_eq!(generator.next_id(), 101);
+        assert_eq!(generator.next_id(), 102);
+    }
+
+    #[test]
+    fn random() {
+        let generator = TagIdGenerator::default();
+        assert_ne!(generator.next_id(), generator.next_id());
+    }
+}
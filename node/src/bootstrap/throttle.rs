@@ -50,6 +50,14 @@ impl Throttle {
         self.successes
     }
 
+    /// Percentage of tracked samples that retrieved something
+    pub fn success_ratio_percent(&self) -> usize {
+        if self.samples.is_empty() {
+            return 0;
+        }
+        self.successes * 100 / self.samples.len()
+    }
+
     fn pop(&mut self) {
         if let Some(sample) = self.samples.pop_front() {
             if sample {
@@ -97,4 +105,16 @@ mod tests {
         throttle.add(false);
         assert_eq!(throttle.throttled(), true);
     }
+
+    #[test]
+    fn success_ratio() {
+        let mut throttle = Throttle::new(4);
+        assert_eq!(throttle.success_ratio_percent(), 100);
+        throttle.add(false);
+        assert_eq!(throttle.success_ratio_percent(), 75);
+        throttle.add(false);
+        throttle.add(false);
+        throttle.add(false);
+        assert_eq!(throttle.success_ratio_percent(), 0);
+    }
 }
@@ -9,16 +9,21 @@ pub struct BootstrapToml {
     pub enable_dependency_walker: Option<bool>,
     pub enable_frontier_scan: Option<bool>,
     pub block_processor_threshold: Option<usize>,
+    pub block_processor_threshold_ratio: Option<f64>,
     pub database_rate_limit: Option<usize>,
     pub database_warmup_ratio: Option<usize>,
     pub max_pull_count: Option<usize>,
     pub channel_limit: Option<usize>,
     pub rate_limit: Option<usize>,
     pub throttle_coefficient: Option<usize>,
+    pub throttle_min: Option<usize>,
+    pub throttle_max: Option<usize>,
     pub throttle_wait: Option<u64>,
     pub request_timeout: Option<u64>,
+    pub request_timeout_jitter: Option<u8>,
     pub max_requests: Option<usize>,
     pub optimistic_request_percentage: Option<u8>,
+    pub channel_exploration_probability: Option<f64>,
     pub account_sets: Option<AccountSetsToml>,
 }
 
@@ -35,12 +40,17 @@ impl From<&BootstrapConfig> for BootstrapToml {
             database_warmup_ratio: Some(config.database_warmup_ratio),
             max_pull_count: Some(config.max_pull_count),
             request_timeout: Some(config.request_timeout.as_millis() as u64),
+            request_timeout_jitter: Some(config.request_timeout_jitter),
             throttle_coefficient: Some(config.throttle_coefficient),
+            throttle_min: Some(config.throttle_min),
+            throttle_max: Some(config.throttle_max),
             throttle_wait: Some(config.throttle_wait.as_millis() as u64),
             account_sets: Some((&config.account_sets).into()),
-            block_processor_threshold: Some(config.block_processor_theshold),
+            block_processor_threshold: Some(config.block_processor_threshold),
+            block_processor_threshold_ratio: config.block_processor_threshold_ratio,
             max_requests: Some(config.max_requests),
             optimistic_request_percentage: Some(config.optimistic_request_percentage),
+            channel_exploration_probability: Some(config.channel_exploration_probability),
         }
     }
 }
@@ -51,6 +61,7 @@ pub struct AccountSetsToml {
     pub consideration_count: Option<usize>,
     pub cooldown: Option<u64>,
     pub priorities_max: Option<usize>,
+    pub priority_half_life: Option<u64>,
 }
 
 impl Default for AccountSetsToml {
@@ -61,6 +72,7 @@ impl Default for AccountSetsToml {
             priorities_max: Some(config.priorities_max),
             blocking_max: Some(config.blocking_max),
             cooldown: Some(config.cooldown.as_millis() as u64),
+            priority_half_life: Some(config.priority_half_life.as_millis() as u64),
         }
     }
 }
@@ -72,6 +84,7 @@ impl From<&AccountSetsConfig> for AccountSetsToml {
             priorities_max: Some(value.priorities_max),
             blocking_max: Some(value.blocking_max),
             cooldown: Some(value.cooldown.as_millis() as u64),
+            priority_half_life: Some(value.priority_half_life.as_millis() as u64),
         }
     }
 }
@@ -92,6 +105,9 @@ impl From<&AccountSetsToml> for AccountSetsConfig {
         if let Some(cooldown) = &toml.cooldown {
             config.cooldown = Duration::from_millis(*cooldown);
         }
+        if let Some(half_life) = &toml.priority_half_life {
+            config.priority_half_life = Duration::from_millis(*half_life);
+        }
         config
     }
 }
@@ -176,6 +176,7 @@ mod tests {
         [node.statistics.log]
         filename_counters = "devcounters.stat"
         filename_samples = "devsamples.stat"
+        format = "csv"
         headers = false
         interval_counters = 999
         interval_samples = 999
@@ -225,13 +226,17 @@ mod tests {
         enable_database_scan = false
         enable_dependency_walker = false
         block_processor_threshold = 999
+        block_processor_threshold_ratio = 0.5
         database_rate_limit = 999
         max_pull_count = 999
         channel_limit = 999
         rate_limit = 999
         throttle_coefficient = 999
+        throttle_min = 999
+        throttle_max = 999
         throttle_wait = 999
         request_timeout = 999
+        request_timeout_jitter = 99
         max_requests = 999
 
         [node.bootstrap.account_sets]
@@ -239,6 +244,7 @@ mod tests {
         consideration_count = 999
         cooldown = 999
         priorities_max = 999
+        priority_half_life = 999
 
         [node.bootstrap_server]
         max_queue = 999
@@ -723,6 +729,10 @@ mod tests {
             deserialized.node.stat_config.log_rotation_count,
             default_cfg.node.stat_config.log_rotation_count
         );
+        assert_ne!(
+            deserialized.node.stat_config.log_format,
+            default_cfg.node.stat_config.log_format
+        );
 
         // WebSocket section
         assert_ne!(
@@ -826,8 +836,12 @@ mod tests {
 
         // Bootstrap Ascending section
         assert_ne!(
-            deserialized.node.bootstrap.block_processor_theshold,
-            default_cfg.node.bootstrap.block_processor_theshold
+            deserialized.node.bootstrap.block_processor_threshold,
+            default_cfg.node.bootstrap.block_processor_threshold
+        );
+        assert_ne!(
+            deserialized.node.bootstrap.block_processor_threshold_ratio,
+            default_cfg.node.bootstrap.block_processor_threshold_ratio
         );
         assert_ne!(
             deserialized.node.bootstrap.database_rate_limit,
@@ -849,6 +863,14 @@ mod tests {
             deserialized.node.bootstrap.throttle_coefficient,
             default_cfg.node.bootstrap.throttle_coefficient
         );
+        assert_ne!(
+            deserialized.node.bootstrap.throttle_min,
+            default_cfg.node.bootstrap.throttle_min
+        );
+        assert_ne!(
+            deserialized.node.bootstrap.throttle_max,
+            default_cfg.node.bootstrap.throttle_max
+        );
         assert_ne!(
             deserialized.node.bootstrap.throttle_wait,
             default_cfg.node.bootstrap.throttle_wait
@@ -857,6 +879,10 @@ mod tests {
             deserialized.node.bootstrap.request_timeout,
             default_cfg.node.bootstrap.request_timeout
         );
+        assert_ne!(
+            deserialized.node.bootstrap.request_timeout_jitter,
+            default_cfg.node.bootstrap.request_timeout_jitter
+        );
 
         // Bootstrap Ascending Account Sets section
         assert_ne!(
@@ -875,6 +901,10 @@ mod tests {
             deserialized.node.bootstrap.account_sets.priorities_max,
             default_cfg.node.bootstrap.account_sets.priorities_max
         );
+        assert_ne!(
+            deserialized.node.bootstrap.account_sets.priority_half_life,
+            default_cfg.node.bootstrap.account_sets.priority_half_life
+        );
 
         // Bootstrap Server section
         assert_ne!(
@@ -250,7 +250,16 @@ impl NodeConfig {
                 config.account_sets = account_sets.into();
             }
             if let Some(block_wait_count) = ascending_toml.block_processor_threshold {
-                config.block_processor_theshold = block_wait_count;
+                config.block_processor_threshold = block_wait_count;
+            }
+            if let Some(ratio) = ascending_toml.block_processor_threshold_ratio {
+                // The threshold can't exceed the queue capacity. Invalid ratios fall back
+                // to the absolute threshold
+                config.block_processor_threshold_ratio = if ratio.is_nan() || ratio <= 0.0 {
+                    None
+                } else {
+                    Some(ratio.min(1.0))
+                };
             }
             if let Some(database_rate_limit) = ascending_toml.database_rate_limit {
                 config.database_rate_limit = database_rate_limit;
@@ -270,18 +279,31 @@ impl NodeConfig {
             if let Some(timeout) = &ascending_toml.request_timeout {
                 config.request_timeout = Duration::from_millis(*timeout);
             }
+            if let Some(jitter) = ascending_toml.request_timeout_jitter {
+                config.request_timeout_jitter = jitter;
+            }
             if let Some(throttle_wait) = &ascending_toml.throttle_wait {
                 config.throttle_wait = Duration::from_millis(*throttle_wait);
             }
             if let Some(throttle_coefficient) = ascending_toml.throttle_coefficient {
                 config.throttle_coefficient = throttle_coefficient;
             }
+            if let Some(throttle_min) = ascending_toml.throttle_min {
+                // The throttle needs room for at least one sample
+                config.throttle_min = throttle_min.max(1);
+            }
+            if let Some(throttle_max) = ascending_toml.throttle_max {
+                config.throttle_max = throttle_max;
+            }
             if let Some(max) = ascending_toml.max_requests {
                 config.max_requests = max;
             }
             if let Some(percent) = ascending_toml.optimistic_request_percentage {
                 config.optimistic_request_percentage = percent;
             }
+            if let Some(probability) = ascending_toml.channel_exploration_probability {
+                config.channel_exploration_probability = probability;
+            }
         }
         if let Some(bootstrap_server_toml) = &toml.bootstrap_server {
             self.bootstrap_server = bootstrap_server_toml.into();
@@ -462,6 +484,7 @@ mod tests {
             consideration_count: Some(201),
             cooldown: Some(203),
             priorities_max: Some(204),
+            priority_half_life: Some(205),
         };
 
         let ascending_toml = BootstrapToml {
@@ -470,15 +493,20 @@ mod tests {
             enable_dependency_walker: Some(false),
             enable_frontier_scan: Some(false),
             block_processor_threshold: Some(100),
+            block_processor_threshold_ratio: Some(0.5),
             database_rate_limit: Some(101),
             max_pull_count: Some(102),
             channel_limit: Some(103),
             rate_limit: Some(200),
             throttle_coefficient: Some(104),
+            throttle_min: Some(109),
+            throttle_max: Some(110),
             throttle_wait: Some(105),
             request_timeout: Some(106),
+            request_timeout_jitter: Some(12),
             max_requests: Some(107),
             optimistic_request_percentage: Some(42),
+            channel_exploration_probability: Some(0.25),
             database_warmup_ratio: Some(108),
             account_sets: Some(sets_toml),
         };
@@ -496,16 +524,21 @@ mod tests {
         assert_eq!(ascending.enable_database_scan, false);
         assert_eq!(ascending.enable_dependency_walker, false);
         assert_eq!(ascending.enable_frontier_scan, false);
-        assert_eq!(ascending.block_processor_theshold, 100);
+        assert_eq!(ascending.block_processor_threshold, 100);
+        assert_eq!(ascending.block_processor_threshold_ratio, Some(0.5));
         assert_eq!(ascending.database_rate_limit, 101);
         assert_eq!(ascending.max_pull_count, 102);
         assert_eq!(ascending.channel_limit, 103);
         assert_eq!(ascending.rate_limit, 200);
         assert_eq!(ascending.throttle_coefficient, 104);
+        assert_eq!(ascending.throttle_min, 109);
+        assert_eq!(ascending.throttle_max, 110);
         assert_eq!(ascending.throttle_wait, Duration::from_millis(105));
         assert_eq!(ascending.request_timeout, Duration::from_millis(106));
+        assert_eq!(ascending.request_timeout_jitter, 12);
         assert_eq!(ascending.max_requests, 107);
         assert_eq!(ascending.optimistic_request_percentage, 42);
+        assert_eq!(ascending.channel_exploration_probability, 0.25);
         assert_eq!(ascending.database_warmup_ratio, 108);
 
         let sets = &cfg.bootstrap.account_sets;
@@ -513,6 +546,35 @@ mod tests {
         assert_eq!(sets.consideration_count, 201);
         assert_eq!(sets.cooldown, Duration::from_millis(203));
         assert_eq!(sets.priorities_max, 204);
+        assert_eq!(sets.priority_half_life, Duration::from_millis(205));
+    }
+
+    #[test]
+    fn sanitize_block_processor_threshold_ratio() {
+        let merged_ratio = |ratio: &str| {
+            let toml: NodeToml = toml::from_str(&format!(
+                "[bootstrap]\nblock_processor_threshold_ratio = {}",
+                ratio
+            ))
+            .unwrap();
+            let mut cfg = NodeConfig::new_test_instance();
+            cfg.merge_toml(&toml);
+            cfg.bootstrap.block_processor_threshold_ratio
+        };
+
+        assert_eq!(merged_ratio("0.5"), Some(0.5));
+        assert_eq!(merged_ratio("2.0"), Some(1.0));
+        assert_eq!(merged_ratio("0.0"), None);
+        assert_eq!(merged_ratio("-0.5"), None);
+        assert_eq!(merged_ratio("nan"), None);
+    }
+
+    #[test]
+    fn throttle_min_is_at_least_one() {
+        let toml: NodeToml = toml::from_str("[bootstrap]\nthrottle_min = 0").unwrap();
+        let mut cfg = NodeConfig::new_test_instance();
+        cfg.merge_toml(&toml);
+        assert_eq!(cfg.bootstrap.throttle_min, 1);
     }
 
     #[test]
@@ -525,15 +587,20 @@ mod tests {
         assert_eq!(ascending_toml.enable_frontier_scan, Some(true));
         assert_eq!(ascending_toml.enable_dependency_walker, Some(true));
         assert_eq!(ascending_toml.block_processor_threshold, Some(1000));
+        assert_eq!(ascending_toml.block_processor_threshold_ratio, None);
         assert_eq!(ascending_toml.database_rate_limit, Some(256));
         assert_eq!(ascending_toml.database_warmup_ratio, Some(10));
         assert_eq!(ascending_toml.max_pull_count, Some(128));
         assert_eq!(ascending_toml.channel_limit, Some(16));
         assert_eq!(ascending_toml.throttle_coefficient, Some(1024 * 8));
+        assert_eq!(ascending_toml.throttle_min, Some(16));
+        assert_eq!(ascending_toml.throttle_max, Some(128 * 1024));
         assert_eq!(ascending_toml.throttle_wait, Some(100));
         assert_eq!(ascending_toml.request_timeout, Some(3000));
+        assert_eq!(ascending_toml.request_timeout_jitter, Some(0));
         assert_eq!(ascending_toml.max_requests, Some(1024));
         assert_eq!(ascending_toml.optimistic_request_percentage, Some(75));
+        assert_eq!(ascending_toml.channel_exploration_probability, Some(0.1));
 
         let sets_toml = ascending_toml.account_sets.as_ref().unwrap();
         assert_eq!(sets_toml.consideration_count, Some(4));
@@ -1,17 +1,36 @@
-use crate::stats::StatsConfig;
-use serde::{Deserialize, Serialize};
+use crate::stats::{StatsConfig, StatsLogFormat};
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
 use std::time::Duration;
 
 #[derive(Deserialize, Serialize)]
 pub struct LogToml {
     pub filename_counters: Option<String>,
     pub filename_samples: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_log_format")]
+    pub format: Option<String>,
     pub headers: Option<bool>,
     pub interval_counters: Option<u64>,
     pub interval_samples: Option<u64>,
     pub rotation_count: Option<usize>,
 }
 
+/// Rejects unknown log formats instead of silently falling back to text
+fn deserialize_log_format<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let format = Option::<String>::deserialize(deserializer)?;
+    if let Some(format) = &format {
+        if StatsLogFormat::parse(format).is_none() {
+            return Err(D::Error::custom(format!(
+                "invalid stats log format \"{}\", expected \"text\" or \"csv\"",
+                format
+            )));
+        }
+    }
+    Ok(format)
+}
+
 impl Default for LogToml {
     fn default() -> Self {
         let config = StatsConfig::default();
@@ -58,6 +77,9 @@ impl From<&StatsToml> for StatsConfig {
             if let Some(log_samples_interval) = &log.interval_samples {
                 config.log_samples_interval = Duration::from_millis(*log_samples_interval);
             }
+            if let Some(format) = log.format.as_deref().and_then(StatsLogFormat::parse) {
+                config.log_format = format;
+            }
         }
         config
     }
@@ -81,6 +103,25 @@ impl From<&StatsConfig> for LogToml {
             headers: Some(config.log_headers),
             filename_counters: Some(config.log_counters_filename.clone()),
             filename_samples: Some(config.log_samples_filename.clone()),
+            format: Some(config.log_format.as_str().to_owned()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_log_format() {
+        let toml: StatsToml = toml::from_str("[log]\nformat = \"csv\"").unwrap();
+        let config = StatsConfig::from(&toml);
+        assert_eq!(config.log_format, StatsLogFormat::Csv);
+    }
+
+    #[test]
+    fn reject_unknown_log_format() {
+        let result = toml::from_str::<StatsToml>("[log]\nformat = \"CSV\"");
+        assert!(result.is_err());
+    }
+}
@@ -3,7 +3,9 @@ use crate::{
         BacklogPopulation, BlockProcessor, BlockProcessorCleanup, BlockSource,
         LocalBlockBroadcaster, LocalBlockBroadcasterExt, UncheckedMap,
     },
-    bootstrap::{BootstrapExt, BootstrapServer, BootstrapServerCleanup, BootstrapService},
+    bootstrap::{
+        BootstrapExt, BootstrapServer, BootstrapServerCleanup, BootstrapService, TagIdGenerator,
+    },
     cementation::ConfirmingSet,
     config::{GlobalConfig, NodeConfig, NodeFlags},
     consensus::{
@@ -30,7 +32,8 @@ use crate::{
         RealtimeMessageHandler, SynCookies,
     },
     utils::{
-        LongRunningTransactionLogger, ThreadPool, ThreadPoolImpl, TimerThread, TxnTrackingConfig,
+        LongRunningTransactionLogger, ShutdownOutcome, ShutdownSequence, ThreadPool,
+        ThreadPoolImpl, TimerThread, TxnTrackingConfig,
     },
     wallets::{Wallets, WalletsExt},
     work::DistributedWorkFactory,
@@ -198,6 +201,23 @@ impl Node {
         let node_id = node_id_key_file.initialize(&application_path).unwrap();
 
         let stats = Arc::new(Stats::new(config.stat_config.clone()));
+        let max_samples = config.stat_config.max_samples;
+        stats.enable_rate_sampling_all(&[
+            (
+                StatType::BlockprocessorResult,
+                DetailType::Progress,
+                Direction::In,
+                1000,
+                max_samples,
+            ),
+            (
+                StatType::ConfirmingSet,
+                DetailType::Cemented,
+                Direction::In,
+                1000,
+                max_samples,
+            ),
+        ]);
 
         let store = if is_nulled {
             Arc::new(LmdbStore::new_null())
@@ -278,6 +298,7 @@ impl Node {
             network_info.clone(),
             network_params.network.cleanup_cutoff(),
         );
+        dead_channel_cleanup.set_observer(network_observer.clone());
 
         let mut network_filter = NetworkFilter::new(1024 * 1024);
         network_filter.age_cutoff = config.network_duplicate_filter_cutoff;
@@ -702,6 +723,7 @@ impl Node {
             message_publisher.clone(),
             global_config.node_config.bootstrap.clone(),
             steady_clock.clone(),
+            TagIdGenerator::default(),
         ));
 
         let local_block_broadcaster = Arc::new(LocalBlockBroadcaster::new(
@@ -1337,9 +1359,81 @@ impl Node {
     }
 }
 
+const DEFAULT_STOP_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// All components of the node in the order in which they have to be stopped
+fn shutdown_sequence(node: &Arc<Node>, timeout_per_component: Duration) -> ShutdownSequence {
+    let mut sequence = ShutdownSequence::new(timeout_per_component);
+
+    // Stop accepting new connections first
+    let n = node.clone();
+    sequence.add("tcp listener", move || {
+        n.tcp_listener.stop();
+        n.peer_connector.stop();
+    });
+
+    // Cancels ongoing work generation tasks, which may be blocking other threads
+    // No tasks may wait for work generation in I/O threads, or termination signal capturing will be unable to call node::stop()
+    let n = node.clone();
+    sequence.add("distributed work", move || n.distributed_work.stop());
+
+    let n = node.clone();
+    sequence.add("wallets", move || n.wallets.stop());
+    let n = node.clone();
+    sequence.add("election schedulers", move || n.election_schedulers.stop());
+    let n = node.clone();
+    sequence.add("bootstrap", move || n.bootstrap.stop());
+    let n = node.clone();
+    sequence.add("rep crawler", move || n.rep_crawler.stop());
+    let n = node.clone();
+    sequence.add("block processor", move || n.block_processor.stop());
+
+    let n = node.clone();
+    sequence.add("remaining components", move || {
+        n.vote_router.stop();
+        n.ledger_pruning.stop();
+        n.peer_cache_connector.stop();
+        n.peer_cache_updater.stop();
+        n.backlog_population.stop();
+        n.unchecked.stop();
+        n.request_aggregator.stop();
+        n.vote_cache_processor.stop();
+        n.vote_processor.stop();
+        n.rep_tiers.stop();
+        n.active.stop();
+        n.vote_generators.stop();
+        n.confirming_set.stop();
+        n.telemetry.stop();
+        n.bootstrap_server.stop();
+        n.stats.stop();
+        n.local_block_broadcaster.stop();
+    });
+
+    // Stop network last to avoid killing in-use sockets
+    let n = node.clone();
+    sequence.add("channels", move || {
+        n.message_processor.lock().unwrap().stop();
+        n.network_threads.lock().unwrap().stop();
+    });
+
+    let n = node.clone();
+    sequence.add("workers", move || {
+        n.monitor.stop();
+        n.wallet_workers.stop();
+        n.election_workers.stop();
+        n.workers.stop();
+        // work pool is not stopped on purpose due to testing setup
+    });
+
+    sequence
+}
+
 pub trait NodeExt {
     fn start(&self);
     fn stop(&self);
+    /// Stops the node in dependency order. Each component gets at most
+    /// `timeout_per_component` to stop, so a wedged component cannot hang the shutdown.
+    fn shutdown(&self, timeout_per_component: Duration) -> Vec<(&'static str, ShutdownOutcome)>;
     fn ongoing_online_weight_calculation_queue(&self);
     fn ongoing_online_weight_calculation(&self);
     fn backup_wallet(&self);
@@ -1442,53 +1536,24 @@ impl NodeExt for Arc<Node> {
     }
 
     fn stop(&self) {
+        self.shutdown(DEFAULT_STOP_TIMEOUT);
+    }
+
+    fn shutdown(&self, timeout_per_component: Duration) -> Vec<(&'static str, ShutdownOutcome)> {
         self.start_stop_listener.emit("stop");
         if self.is_nulled {
-            return; // TODO better nullability implementation
+            return Vec::new(); // TODO better nullability implementation
         }
 
         // Ensure stop can only be called once
         if self.stopped.swap(true, Ordering::SeqCst) {
-            return;
+            return Vec::new();
         }
         info!("Node stopping...");
 
-        self.tcp_listener.stop();
-        self.vote_router.stop();
-        self.peer_connector.stop();
-        self.ledger_pruning.stop();
-        self.peer_cache_connector.stop();
-        self.peer_cache_updater.stop();
-        // Cancels ongoing work generation tasks, which may be blocking other threads
-        // No tasks may wait for work generation in I/O threads, or termination signal capturing will be unable to call node::stop()
-        self.distributed_work.stop();
-        self.backlog_population.stop();
-        self.bootstrap.stop();
-        self.rep_crawler.stop();
-        self.unchecked.stop();
-        self.block_processor.stop();
-        self.request_aggregator.stop();
-        self.vote_cache_processor.stop();
-        self.vote_processor.stop();
-        self.rep_tiers.stop();
-        self.election_schedulers.stop();
-        self.active.stop();
-        self.vote_generators.stop();
-        self.confirming_set.stop();
-        self.telemetry.stop();
-        self.bootstrap_server.stop();
-        self.wallets.stop();
-        self.stats.stop();
-        self.local_block_broadcaster.stop();
-        self.message_processor.lock().unwrap().stop();
-        self.network_threads.lock().unwrap().stop(); // Stop network last to avoid killing in-use sockets
-        self.monitor.stop();
-
-        self.wallet_workers.stop();
-        self.election_workers.stop();
-        self.workers.stop();
-
-        // work pool is not stopped on purpose due to testing setup
+        let results = shutdown_sequence(self, timeout_per_component).run();
+        info!("Node stopped");
+        results
     }
 
     fn ongoing_online_weight_calculation_queue(&self) {
@@ -1681,6 +1746,35 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn shutdown_stops_components_in_order() {
+        let node = TestNode::new().await;
+        node.start();
+
+        let results = node.shutdown(Duration::from_secs(30));
+
+        let names: Vec<_> = results.iter().map(|(name, _)| *name).collect();
+        assert_eq!(
+            names,
+            vec![
+                "tcp listener",
+                "distributed work",
+                "wallets",
+                "election schedulers",
+                "bootstrap",
+                "rep crawler",
+                "block processor",
+                "remaining components",
+                "channels",
+                "workers"
+            ]
+        );
+        assert!(results
+            .iter()
+            .all(|(_, outcome)| *outcome == ShutdownOutcome::Stopped));
+        assert_eq!(node.peer_cache_updater.is_running(), false);
+    }
+
     struct TestNode {
         app_path: PathBuf,
         node: Arc<Node>,
@@ -13,6 +13,7 @@ use rsnano_core::{utils::ContainerInfo, Amount, PublicKey};
 use rsnano_ledger::RepWeightCache;
 use rsnano_network::ChannelId;
 use rsnano_nullable_clock::Timestamp;
+use rsnano_output_tracker::{OutputListenerMt, OutputTrackerMt};
 use std::{cmp::max, sync::Arc, time::Duration};
 use {online_container::OnlineContainer, peered_container::PeeredContainer};
 
@@ -28,6 +29,7 @@ pub struct OnlineReps {
     online_weight: Amount,
     weight_period: Duration,
     online_weight_minimum: Amount,
+    observed_directly_listener: OutputListenerMt<PublicKey>,
 }
 
 impl OnlineReps {
@@ -44,6 +46,7 @@ impl OnlineReps {
             online_weight: Amount::zero(),
             weight_period,
             online_weight_minimum,
+            observed_directly_listener: OutputListenerMt::new(),
         }
     }
 
@@ -199,11 +202,16 @@ impl OnlineReps {
         channel_id: ChannelId,
         now: Timestamp,
     ) -> InsertResult {
+        self.observed_directly_listener.emit(rep_account);
         self.vote_observed(rep_account, now);
         self.peered_reps
             .update_or_insert(rep_account, channel_id, now)
     }
 
+    pub fn track_vote_observed_directly(&self) -> Arc<OutputTrackerMt<PublicKey>> {
+        self.observed_directly_listener.track()
+    }
+
     pub fn remove_peer(&mut self, channel_id: ChannelId) -> Vec<PublicKey> {
         self.peered_reps.remove(channel_id)
     }
@@ -16,7 +16,8 @@ use rsnano_messages::{ConfirmReq, Message};
 use rsnano_network::{ChannelId, ChannelInfo, DropPolicy, NetworkInfo, TrafficType};
 use rsnano_nullable_clock::{SteadyClock, Timestamp};
 use std::{
-    collections::HashMap,
+    cmp::max,
+    collections::{HashMap, HashSet},
     mem::size_of,
     ops::DerefMut,
     sync::{Arc, Condvar, Mutex, MutexGuard, RwLock},
@@ -87,6 +88,8 @@ impl RepCrawler {
                 stopped: false,
                 last_query: None,
                 responses: BoundedVecDeque::new(Self::MAX_RESPONSES),
+                reply_times: HashMap::new(),
+                probes: Vec::new(),
             }),
             tokio,
         }
@@ -111,6 +114,7 @@ impl RepCrawler {
         let mut processed = false;
 
         let query_timeout = guard.query_timeout;
+        let mut reply_time = None;
         let x = guard.deref_mut();
         let queries = &mut x.queries;
         let responses = &mut x.responses;
@@ -128,11 +132,13 @@ impl RepCrawler {
                 self.stats
                     .inc_dir(StatType::RepCrawler, DetailType::Response, Direction::In);
 
+                let elapsed = query.time.elapsed();
                 self.stats.sample(
                     Sample::RepResponseTime,
-                    query.time.elapsed().as_millis() as i64,
+                    elapsed.as_millis() as i64,
                     (0, query_timeout.as_millis() as i64),
                 );
+                reply_time = Some(elapsed);
 
                 responses.push_back((channel_id, Arc::clone(&vote)));
                 query.replies += 1;
@@ -146,6 +152,10 @@ impl RepCrawler {
             done
         });
 
+        if let Some(elapsed) = reply_time {
+            guard.record_reply_time(channel_id, elapsed);
+        }
+
         processed
     }
 
@@ -161,6 +171,10 @@ impl RepCrawler {
             return;
         };
 
+        self.send_queries(hash_root, target_channels);
+    }
+
+    fn send_queries(&self, hash_root: (BlockHash, Root), target_channels: Vec<Arc<ChannelInfo>>) {
         let mut guard = self.rep_crawler_impl.lock().unwrap();
 
         for channel in target_channels {
@@ -184,6 +198,43 @@ impl RepCrawler {
         }
     }
 
+    /// Checks whether the given representative is online by requesting a vote from all channels.
+    /// The callback is called with `true` as soon as the representative votes for the queried block
+    /// or with `false` if it didn't vote within the query timeout.
+    /// Returns the hash of the queried block
+    pub fn probe_representative(
+        &self,
+        account: Account,
+        callback: Box<dyn FnOnce(bool) + Send>,
+    ) -> Option<BlockHash> {
+        let Some(hash_root) = self.prepare_query_target() else {
+            debug!("No block to probe representative");
+            self.stats.inc_dir(
+                StatType::RepCrawler,
+                DetailType::QueryTargetFailed,
+                Direction::In,
+            );
+            callback(false);
+            return None;
+        };
+
+        self.rep_crawler_impl
+            .lock()
+            .unwrap()
+            .probes
+            .push(RepresentativeProbe {
+                account,
+                hash: hash_root.0,
+                started: Instant::now(),
+                callback,
+            });
+
+        let channels = self.network_info.read().unwrap().list_realtime_channels(0);
+        self.send_queries(hash_root, channels);
+        self.condition.notify_all();
+        Some(hash_root.0)
+    }
+
     /// Attempt to determine if the peer manages one or more representative accounts
     pub fn query_channel(&self, target_channel: Arc<ChannelInfo>) {
         self.query(vec![target_channel]);
@@ -196,6 +247,16 @@ impl RepCrawler {
         guard.responses.push_back((channel_id, vote));
     }
 
+    // Only for tests. All responses are queued at once, so that they are processed in one batch
+    pub fn force_process_batch(&self, responses: Vec<(Arc<Vote>, ChannelId)>) {
+        assert!(self.network_params.network.is_dev_network());
+        let mut guard = self.rep_crawler_impl.lock().unwrap();
+        for (vote, channel_id) in responses {
+            guard.responses.push_back((channel_id, vote));
+        }
+        self.condition.notify_all();
+    }
+
     // Only for tests
     pub fn force_query(&self, hash: BlockHash, channel_id: ChannelId) {
         assert!(self.network_params.network.is_dev_network());
@@ -256,6 +317,15 @@ impl RepCrawler {
 
             guard.cleanup();
 
+            let expired_probes = guard.take_expired_probes();
+            if !expired_probes.is_empty() {
+                drop(guard);
+                for probe in expired_probes {
+                    (probe.callback)(false);
+                }
+                guard = self.rep_crawler_impl.lock().unwrap();
+            }
+
             if guard.query_predicate(interval) {
                 guard.last_query = Some(Instant::now());
                 drop(guard);
@@ -294,23 +364,46 @@ impl RepCrawler {
     fn validate_and_process<'a>(&self, mut guard: MutexGuard<RepCrawlerImpl>) {
         let mut responses = BoundedVecDeque::new(Self::MAX_RESPONSES);
         std::mem::swap(&mut guard.responses, &mut responses);
+        let responses: Vec<_> = responses
+            .into_iter()
+            .filter(|(channel_id, _)| {
+                if *channel_id == ChannelId::LOOPBACK {
+                    debug!("Ignoring vote from loopback channel");
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect();
+
+        // Probes are matched against all responses, because a duplicate vote
+        // of the same representative could otherwise hide the probe's reply
+        let completed_probes = guard.take_answered_probes(&responses);
         drop(guard);
+        for probe in completed_probes {
+            (probe.callback)(true);
+        }
+
+        // The weights are looked up before the online reps get locked
+        let responses: Vec<_> = deduplicate_responses(responses)
+            .into_iter()
+            .map(|(channel_id, vote)| {
+                let rep_weight = self.ledger.weight(&vote.voting_account);
+                (channel_id, vote, rep_weight)
+            })
+            .collect();
 
         // normally the rep_crawler only tracks principal reps but it can be made to track
         // reps with less weight by setting rep_crawler_weight_minimum to a low value
+        let mut online_reps = self.online_reps.lock().unwrap();
         let minimum = std::cmp::min(
-            self.online_reps.lock().unwrap().minimum_principal_weight(),
+            online_reps.minimum_principal_weight(),
             self.config.rep_crawler_weight_minimum,
         );
 
-        // TODO: Is it really faster to repeatedly lock/unlock the mutex for each response?
-        for (channel_id, vote) in responses {
-            if channel_id == ChannelId::LOOPBACK {
-                debug!("Ignoring vote from loopback channel");
-                continue;
-            }
-
-            let rep_weight = self.ledger.weight(&vote.voting_account);
+        // The whole batch is processed under a single lock
+        let now = self.steady_clock.now();
+        for (channel_id, vote, rep_weight) in responses {
             if rep_weight < minimum {
                 debug!(
                     "Ignoring vote from account: {} with too little voting weight: {}",
@@ -320,11 +413,7 @@ impl RepCrawler {
                 continue;
             }
 
-            let result = self.online_reps.lock().unwrap().vote_observed_directly(
-                vote.voting_account,
-                channel_id,
-                self.steady_clock.now(),
-            );
+            let result = online_reps.vote_observed_directly(vote.voting_account, channel_id, now);
 
             match result {
                 InsertResult::Inserted => {
@@ -426,9 +515,52 @@ struct RepCrawlerImpl {
     last_query: Option<Instant>,
     responses: BoundedVecDeque<(ChannelId, Arc<Vote>)>,
     is_dev_network: bool,
+    /// Exponential moving average of the reply time per channel
+    reply_times: HashMap<ChannelId, Duration>,
+    probes: Vec<RepresentativeProbe>,
 }
 
 impl RepCrawlerImpl {
+    const MAX_REPLY_TIMES: usize = 1024 * 4;
+
+    fn take_completed_probes(&mut self, vote: &Vote) -> Vec<RepresentativeProbe> {
+        let voting_account = Account::from(vote.voting_account);
+        let (completed, pending) =
+            std::mem::take(&mut self.probes)
+                .into_iter()
+                .partition(|probe| {
+                    probe.account == voting_account && vote.hashes.contains(&probe.hash)
+                });
+        self.probes = pending;
+        completed
+    }
+
+    fn take_answered_probes(
+        &mut self,
+        responses: &[(ChannelId, Arc<Vote>)],
+    ) -> Vec<RepresentativeProbe> {
+        responses
+            .iter()
+            .flat_map(|(_, vote)| self.take_completed_probes(vote))
+            .collect()
+    }
+
+    fn take_expired_probes(&mut self) -> Vec<RepresentativeProbe> {
+        let (expired, pending) = std::mem::take(&mut self.probes)
+            .into_iter()
+            .partition(|probe| probe.started.elapsed() >= self.query_timeout);
+        self.probes = pending;
+        expired
+    }
+
+    fn record_reply_time(&mut self, channel_id: ChannelId, elapsed: Duration) {
+        let average = match self.reply_times.get(&channel_id) {
+            Some(average) => (*average * 4 + elapsed) / 5,
+            None => elapsed,
+        };
+        self.reply_times.insert(channel_id, average);
+    }
+
     fn query_predicate(&self, query_interval: Duration) -> bool {
         match &self.last_query {
             Some(last) => last.elapsed() >= query_interval,
@@ -511,7 +643,9 @@ impl RepCrawlerImpl {
     fn cleanup(&mut self) {
         // Evict queries that haven't been responded to in a while
         self.queries.retain(|query| {
-            if query.time.elapsed() < self.query_timeout {
+            let timeout =
+                adaptive_query_timeout(self.query_timeout, self.reply_times.get(&query.channel_id));
+            if query.time.elapsed() < timeout {
                 return true; // Retain
             }
 
@@ -539,9 +673,48 @@ impl RepCrawlerImpl {
 
             false // Retain
         });
+
+        if self.reply_times.len() > Self::MAX_REPLY_TIMES {
+            let queries = &self.queries;
+            self.reply_times
+                .retain(|channel_id, _| queries.count_by_channel(*channel_id) > 0);
+        }
     }
 }
 
+/// Slow channels get more time to reply than the configured query timeout
+fn adaptive_query_timeout(
+    query_timeout: Duration,
+    average_reply_time: Option<&Duration>,
+) -> Duration {
+    match average_reply_time {
+        Some(average) => max(query_timeout, *average * 2),
+        None => query_timeout,
+    }
+}
+
+/// Keeps only the most recent response per voting account
+fn deduplicate_responses(
+    responses: impl IntoIterator<Item = (ChannelId, Arc<Vote>)>,
+) -> Vec<(ChannelId, Arc<Vote>)> {
+    let responses: Vec<_> = responses.into_iter().collect();
+    let mut seen = HashSet::new();
+    let mut result: Vec<_> = responses
+        .into_iter()
+        .rev()
+        .filter(|(_, vote)| seen.insert(vote.voting_account))
+        .collect();
+    result.reverse();
+    result
+}
+
+struct RepresentativeProbe {
+    account: Account,
+    hash: BlockHash,
+    started: Instant,
+    callback: Box<dyn FnOnce(bool) + Send>,
+}
+
 struct QueryEntry {
     hash: BlockHash,
     channel_id: ChannelId,
@@ -603,16 +776,16 @@ impl OrderedQueries {
     fn remove(&mut self, entry_id: usize) {
         if let Some(entry) = self.entries.remove(&entry_id) {
             self.sequenced.retain(|id| *id != entry_id);
-            if let Some(mut by_channel) = self.by_channel.remove(&entry.channel_id) {
-                if by_channel.len() > 1 {
-                    by_channel.retain(|i| *i != entry_id);
-                    self.by_channel.insert(entry.channel_id, by_channel);
+            if let Some(by_channel) = self.by_channel.get_mut(&entry.channel_id) {
+                by_channel.retain(|i| *i != entry_id);
+                if by_channel.is_empty() {
+                    self.by_channel.remove(&entry.channel_id);
                 }
             }
-            if let Some(mut by_hash) = self.by_hash.remove(&entry.hash) {
-                if by_hash.len() > 1 {
-                    by_hash.retain(|i| *i != entry_id);
-                    self.by_hash.insert(entry.hash, by_hash);
+            if let Some(by_hash) = self.by_hash.get_mut(&entry.hash) {
+                by_hash.retain(|i| *i != entry_id);
+                if by_hash.is_empty() {
+                    self.by_hash.remove(&entry.hash);
                 }
             }
         }
@@ -665,3 +838,140 @@ impl RepCrawlerExt for Arc<RepCrawler> {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnano_core::PrivateKey;
+
+    #[test]
+    fn slow_channel_queries_survive_longer() {
+        let mut crawler = create_crawler_impl(Duration::from_millis(100));
+        let fast_channel = ChannelId::from(1);
+        let slow_channel = ChannelId::from(2);
+        crawler.record_reply_time(fast_channel, Duration::from_millis(10));
+        crawler.record_reply_time(slow_channel, Duration::from_millis(500));
+
+        let sent = Instant::now() - Duration::from_millis(300);
+        crawler.queries.insert(create_query(1, fast_channel, sent));
+        crawler.queries.insert(create_query(2, slow_channel, sent));
+
+        crawler.cleanup();
+
+        assert_eq!(crawler.queries.count_by_channel(fast_channel), 0);
+        assert_eq!(crawler.queries.count_by_channel(slow_channel), 1);
+    }
+
+    #[test]
+    fn deduplicate_responses_keeps_most_recent_vote_per_account() {
+        let rep_a = PrivateKey::from(1);
+        let rep_b = PrivateKey::from(2);
+        let old_vote = Arc::new(Vote::new(&rep_a, 1, 0, vec![BlockHash::from(1)]));
+        let other_vote = Arc::new(Vote::new(&rep_b, 1, 0, vec![BlockHash::from(1)]));
+        let new_vote = Arc::new(Vote::new(&rep_a, 2, 0, vec![BlockHash::from(1)]));
+
+        let responses = deduplicate_responses(vec![
+            (ChannelId::from(1), old_vote),
+            (ChannelId::from(2), other_vote.clone()),
+            (ChannelId::from(3), new_vote.clone()),
+        ]);
+
+        assert_eq!(
+            responses,
+            vec![
+                (ChannelId::from(2), other_vote),
+                (ChannelId::from(3), new_vote)
+            ]
+        );
+    }
+
+    #[test]
+    fn probe_is_answered_by_vote_that_has_a_newer_duplicate() {
+        let mut crawler = create_crawler_impl(Duration::from_secs(5));
+        let rep = PrivateKey::from(1);
+        crawler.probes.push(RepresentativeProbe {
+            account: rep.account(),
+            hash: BlockHash::from(1),
+            started: Instant::now(),
+            callback: Box::new(|_| {}),
+        });
+        let probe_reply = Arc::new(Vote::new(&rep, 1, 0, vec![BlockHash::from(1)]));
+        let later_vote = Arc::new(Vote::new(&rep, 2, 0, vec![BlockHash::from(2)]));
+        let responses = vec![
+            (ChannelId::from(1), probe_reply),
+            (ChannelId::from(1), later_vote),
+        ];
+
+        let completed = crawler.take_answered_probes(&responses);
+
+        assert_eq!(completed.len(), 1);
+        assert!(crawler.probes.is_empty());
+        assert_eq!(deduplicate_responses(responses).len(), 1);
+    }
+
+    #[test]
+    fn reply_time_is_averaged() {
+        let mut crawler = create_crawler_impl(Duration::from_millis(100));
+        let channel = ChannelId::from(1);
+        crawler.record_reply_time(channel, Duration::from_millis(100));
+        crawler.record_reply_time(channel, Duration::from_millis(200));
+        assert_eq!(
+            crawler.reply_times.get(&channel),
+            Some(&Duration::from_millis(120))
+        );
+    }
+
+    #[test]
+    fn remove_query_keeps_siblings() {
+        let mut queries = OrderedQueries::new();
+        let channel = ChannelId::from(1);
+        let now = Instant::now();
+        queries.insert(create_query(1, channel, now));
+        queries.insert(create_query(1, channel, now));
+        queries.insert(create_query(2, channel, now));
+
+        queries.remove(1);
+
+        assert_eq!(queries.len(), 2);
+        assert_eq!(queries.count_by_channel(channel), 2);
+        assert_eq!(queries.count_by_block(&BlockHash::from(1)), 1);
+        assert_eq!(queries.count_by_block(&BlockHash::from(2)), 1);
+    }
+
+    #[test]
+    fn remove_last_query_drops_index_keys() {
+        let mut queries = OrderedQueries::new();
+        let channel = ChannelId::from(1);
+        queries.insert(create_query(1, channel, Instant::now()));
+
+        queries.remove(1);
+
+        assert_eq!(queries.len(), 0);
+        assert!(queries.by_channel.is_empty());
+        assert!(queries.by_hash.is_empty());
+    }
+
+    fn create_crawler_impl(query_timeout: Duration) -> RepCrawlerImpl {
+        RepCrawlerImpl {
+            queries: OrderedQueries::new(),
+            online_reps: Arc::new(Mutex::new(OnlineReps::default())),
+            stats: Arc::new(Stats::default()),
+            query_timeout,
+            stopped: false,
+            last_query: None,
+            responses: BoundedVecDeque::new(RepCrawler::MAX_RESPONSES),
+            is_dev_network: true,
+            reply_times: HashMap::new(),
+            probes: Vec::new(),
+        }
+    }
+
+    fn create_query(hash: u64, channel_id: ChannelId, time: Instant) -> QueryEntry {
+        QueryEntry {
+            hash: BlockHash::from(hash),
+            channel_id,
+            time,
+            replies: 0,
+        }
+    }
+}
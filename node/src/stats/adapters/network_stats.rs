@@ -52,6 +52,15 @@ impl NetworkObserver for NetworkStats {
             "Closing channel due to timeout");
     }
 
+    fn handshakes_timed_out(&self, count: usize) {
+        self.0.add_dir(
+            StatType::Tcp,
+            DetailType::HandshakeTimeout,
+            Direction::In,
+            count as u64,
+        );
+    }
+
     fn read_succeeded(&self, count: usize) {
         self.0.add_dir(
             StatType::TrafficTcp,
@@ -5,6 +5,6 @@ mod stats_enums;
 mod stats_log_sink;
 
 pub use stats::*;
-pub use stats_config::StatsConfig;
+pub use stats_config::{StatsConfig, StatsLogFormat};
 pub use stats_enums::*;
-pub use stats_log_sink::{StatFileWriter, StatsJsonWriterV2, StatsLogSink};
+pub use stats_log_sink::{StatCsvWriter, StatFileWriter, StatsJsonWriterV2, StatsLogSink};
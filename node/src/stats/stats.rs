@@ -1,12 +1,12 @@
 use super::{DetailType, Direction, Sample, StatType};
-use super::{StatFileWriter, StatsConfig, StatsLogSink};
+use super::{StatCsvWriter, StatFileWriter, StatsConfig, StatsLogFormat, StatsLogSink};
 use anyhow::Result;
 use bounded_vec_deque::BoundedVecDeque;
 use once_cell::sync::Lazy;
 use rsnano_core::utils::get_env_bool;
 use rsnano_messages::MessageType;
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, HashMap},
     sync::{atomic::AtomicU64, Arc, Condvar, Mutex, RwLock},
     thread::JoinHandle,
     time::{Duration, Instant, SystemTime},
@@ -32,6 +32,8 @@ impl Stats {
         let mutables = Arc::new(RwLock::new(StatMutables {
             counters: BTreeMap::new(),
             samplers: BTreeMap::new(),
+            histograms: BTreeMap::new(),
+            rate_samplers: BTreeMap::new(),
             timestamp: Instant::now(),
         }));
         Self {
@@ -42,6 +44,7 @@ impl Stats {
                 mutables: Arc::clone(&mutables),
                 config,
                 loop_state: Mutex::new(StatsLoopState {
+                    started: false,
                     stopped: false,
                     log_last_count_writeout: Instant::now(),
                     log_last_sample_writeout: Instant::now(),
@@ -53,12 +56,24 @@ impl Stats {
     }
 
     pub fn start(&self) {
-        if !self.should_run() {
-            return;
-        };
+        self.stats_loop.loop_state.lock().unwrap().started = true;
+        if self.should_run() {
+            self.start_thread();
+        }
+    }
+
+    /// Spawns the stats thread if the stats were started and it isn't running yet
+    fn start_thread(&self) {
+        let mut thread = self.thread.lock().unwrap();
+        {
+            let state = self.stats_loop.loop_state.lock().unwrap();
+            if thread.is_some() || !state.started || state.stopped {
+                return;
+            }
+        }
 
         let stats_loop = Arc::clone(&self.stats_loop);
-        *self.thread.lock().unwrap() = Some(
+        *thread = Some(
             std::thread::Builder::new()
                 .name("Stats".to_string())
                 .spawn(move || stats_loop.run())
@@ -67,7 +82,9 @@ impl Stats {
     }
 
     fn should_run(&self) -> bool {
-        !self.config.log_counters_interval.is_zero() || !self.config.log_samples_interval.is_zero()
+        !self.config.log_counters_interval.is_zero()
+            || !self.config.log_samples_interval.is_zero()
+            || !self.mutables.read().unwrap().rate_samplers.is_empty()
     }
 
     /// Stop stats being output
@@ -217,6 +234,136 @@ impl Stats {
         }
     }
 
+    /// Defines a histogram. `intervals` contains the inclusive start of each bin
+    /// in ascending order. The last bin is unbounded.
+    pub fn define_histogram(
+        &self,
+        stat_type: StatType,
+        detail: DetailType,
+        dir: Direction,
+        intervals: &[u64],
+    ) {
+        debug_assert!(intervals.windows(2).all(|w| w[0] < w[1]));
+        let key = CounterKey::new(stat_type, detail, dir);
+        let mut lock = self.mutables.write().unwrap();
+        lock.histograms.insert(key, HistogramEntry::new(intervals));
+    }
+
+    /// Adds `count` to the bin which `value` falls into.
+    /// Does nothing if the histogram wasn't defined.
+    pub fn update_histogram(
+        &self,
+        stat_type: StatType,
+        detail: DetailType,
+        dir: Direction,
+        value: u64,
+        count: u64,
+    ) {
+        let key = CounterKey::new(stat_type, detail, dir);
+        let lock = self.mutables.read().unwrap();
+        if let Some(histogram) = lock.histograms.get(&key) {
+            histogram.add(value, count);
+        }
+    }
+
+    /// Returns the bins of the given histogram or an empty Vec if it wasn't defined
+    pub fn histogram(
+        &self,
+        stat_type: StatType,
+        detail: DetailType,
+        dir: Direction,
+    ) -> Vec<HistogramBin> {
+        let key = CounterKey::new(stat_type, detail, dir);
+        let lock = self.mutables.read().unwrap();
+        lock.histograms
+            .get(&key)
+            .map(|h| h.bins())
+            .unwrap_or_default()
+    }
+
+    /// Starts recording datapoints of the given counter, so that `rate()` can be calculated.
+    /// The stats thread records a datapoint every second.
+    pub fn enable_rate_sampling(&self, stat_type: StatType, detail: DetailType, dir: Direction) {
+        let key = CounterKey::new(stat_type, detail, dir);
+        self.mutables.write().unwrap().enable_rate_sampling(
+            key,
+            Duration::ZERO,
+            self.config.max_samples,
+        );
+        self.start_thread();
+    }
+
+    /// Enables rate sampling for many counters with a single lock acquisition.
+    /// Each entry contains the minimum interval between two datapoints in milliseconds
+    /// and the maximum number of datapoints that are kept.
+    pub fn enable_rate_sampling_all(
+        &self,
+        entries: &[(StatType, DetailType, Direction, usize, usize)],
+    ) {
+        {
+            let mut lock = self.mutables.write().unwrap();
+            for &(stat_type, detail, dir, interval_ms, capacity) in entries {
+                let key = CounterKey::new(stat_type, detail, dir);
+                lock.enable_rate_sampling(key, Duration::from_millis(interval_ms as u64), capacity);
+            }
+        }
+        self.start_thread();
+    }
+
+    /// Records the current value of all counters with enabled rate sampling
+    pub fn record_rate_datapoints(&self, now: Instant) {
+        self.mutables.read().unwrap().record_rate_datapoints(now);
+    }
+
+    /// Per second rate of change of the given counter, calculated from the datapoints
+    /// within `window` before the most recent datapoint.
+    /// Returns 0.0 when fewer than two datapoints are available.
+    pub fn rate(
+        &self,
+        stat_type: StatType,
+        detail: DetailType,
+        dir: Direction,
+        window: Duration,
+    ) -> f64 {
+        let key = CounterKey::new(stat_type, detail, dir);
+        let lock = self.mutables.read().unwrap();
+        let Some(sampler) = lock.rate_samplers.get(&key) else {
+            return 0.0;
+        };
+        let datapoints = sampler.datapoints.lock().unwrap();
+        let Some(&(last_time, last_value)) = datapoints.back() else {
+            return 0.0;
+        };
+        let Some(&(first_time, first_value)) = datapoints
+            .iter()
+            .find(|(time, _)| last_time.duration_since(*time) <= window)
+        else {
+            return 0.0;
+        };
+
+        let elapsed = last_time.duration_since(first_time).as_secs_f64();
+        if elapsed == 0.0 {
+            return 0.0;
+        }
+        last_value.saturating_sub(first_value) as f64 / elapsed
+    }
+
+    /// Returns the current value of all counters
+    pub fn snapshot(&self) -> Vec<StatSample> {
+        let timestamp = SystemTime::now();
+        let lock = self.mutables.read().unwrap();
+        lock.counters
+            .iter()
+            .map(|(key, entry)| StatSample {
+                stat_type: key.stat_type,
+                detail: key.detail,
+                dir: key.dir,
+                value: entry.into(),
+                timestamp,
+            })
+            .collect()
+    }
+
     /// Log counters to the given log link
     pub fn log_counters(&self, sink: &mut dyn StatsLogSink) -> Result<()> {
         let now = SystemTime::now();
@@ -242,9 +389,34 @@ impl Stats {
         let mut lock = self.mutables.write().unwrap();
         lock.counters.clear();
         lock.samplers.clear();
+        for sampler in lock.rate_samplers.values() {
+            sampler.datapoints.lock().unwrap().clear();
+        }
+        for histogram in lock.histograms.values() {
+            histogram.reset();
+        }
         lock.timestamp = Instant::now();
     }
-    ///
+
+    /// Clear a single counter. The aggregated `DetailType::All` counter is
+    /// removed as well when no other detail of that type is left.
+    pub fn clear_one(&self, stat_type: StatType, detail: DetailType, dir: Direction) {
+        let mut lock = self.mutables.write().unwrap();
+        let key = CounterKey::new(stat_type, detail, dir);
+        lock.counters.remove(&key);
+
+        let all_key = CounterKey::new(stat_type, DetailType::All, dir);
+        let other_details_left = lock
+            .counters
+            .range(all_key..)
+            .take_while(|(k, _)| k.stat_type == stat_type)
+            .any(|(k, _)| k.dir == dir && k.detail != DetailType::All);
+
+        if !other_details_left {
+            lock.counters.remove(&all_key);
+        }
+    }
+
     /// Returns current value for the given counter at the type level
     pub fn count_all(&self, stat_type: StatType, dir: Direction) -> u64 {
         let guard = self.mutables.read().unwrap();
@@ -261,6 +433,28 @@ impl Stats {
         result
     }
 
+    /// Total of all details of the given type. Uses the aggregated `DetailType::All`
+    /// counter when it was tracked and sums up the single details otherwise.
+    pub fn count_by_type(&self, stat_type: StatType, dir: Direction) -> u64 {
+        match self.count(stat_type, DetailType::All, dir) {
+            0 => self.count_all(stat_type, dir),
+            aggregate => aggregate,
+        }
+    }
+
+    /// Returns the `StatType::Message` counter of every message type
+    pub fn message_counts(&self, dir: Direction) -> HashMap<MessageType, u64> {
+        let lock = self.mutables.read().unwrap();
+        MESSAGE_TYPES
+            .iter()
+            .map(|&message_type| {
+                let key = CounterKey::new(StatType::Message, message_type.into(), dir);
+                let count = lock.counters.get(&key).map(u64::from).unwrap_or_default();
+                (message_type, count)
+            })
+            .collect()
+    }
+
     /// Returns current value for the given counter at the type level
     pub fn count(&self, stat_type: StatType, detail: DetailType, dir: Direction) -> u64 {
         let key = CounterKey::new(stat_type, detail, dir);
@@ -302,6 +496,15 @@ impl SamplerKey {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatSample {
+    pub stat_type: StatType,
+    pub detail: DetailType,
+    pub dir: Direction,
+    pub value: u64,
+    pub timestamp: SystemTime,
+}
+
 pub enum StatCategory {
     Counters,
     Samples,
@@ -311,12 +514,29 @@ struct StatMutables {
     /// Stat entries are sorted by key to simplify processing of log output
     counters: BTreeMap<CounterKey, CounterEntry>,
     samplers: BTreeMap<SamplerKey, SamplerEntry>,
+    histograms: BTreeMap<CounterKey, HistogramEntry>,
+    /// Datapoints of counters for which rate sampling is enabled
+    rate_samplers: BTreeMap<CounterKey, RateSampler>,
 
     /// Time of last clear() call
     timestamp: Instant,
 }
 
 impl StatMutables {
+    /// Replaces an existing sampler of the counter, so that its settings can be changed
+    fn enable_rate_sampling(&mut self, key: CounterKey, interval: Duration, capacity: usize) {
+        self.counters.entry(key).or_insert(CounterEntry::new());
+        self.rate_samplers
+            .insert(key, RateSampler::new(interval, capacity));
+    }
+
+    fn record_rate_datapoints(&self, now: Instant) {
+        for (key, sampler) in &self.rate_samplers {
+            let value = self.counters.get(key).map(u64::from).unwrap_or_default();
+            sampler.record(now, value);
+        }
+    }
+
     /// Unlocked implementation of log_samples() to avoid using recursive locking
     fn log_samples_impl(
         &self,
@@ -390,6 +610,31 @@ impl From<&CounterEntry> for u64 {
     }
 }
 
+struct RateSampler {
+    /// Minimum time between two recorded datapoints
+    interval: Duration,
+    datapoints: Mutex<BoundedVecDeque<(Instant, u64)>>,
+}
+
+impl RateSampler {
+    fn new(interval: Duration, capacity: usize) -> Self {
+        Self {
+            interval,
+            datapoints: Mutex::new(BoundedVecDeque::new(capacity)),
+        }
+    }
+
+    fn record(&self, now: Instant, value: u64) {
+        let mut datapoints = self.datapoints.lock().unwrap();
+        if let Some(&(last_time, _)) = datapoints.back() {
+            if now.saturating_duration_since(last_time) < self.interval {
+                return;
+            }
+        }
+        datapoints.push_back((now, value));
+    }
+}
+
 struct SamplerEntry {
     samples: Mutex<BoundedVecDeque<i64>>,
     pub expected_min_max: (i64, i64),
@@ -413,6 +658,73 @@ impl SamplerEntry {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistogramBin {
+    /// Inclusive start of the bin
+    pub start: u64,
+    /// Exclusive end of the bin. `u64::MAX` for the last bin
+    pub end: u64,
+    pub count: u64,
+}
+
+struct HistogramEntry {
+    intervals: Vec<u64>,
+    bins: Vec<AtomicU64>,
+}
+
+impl HistogramEntry {
+    fn new(intervals: &[u64]) -> Self {
+        Self {
+            intervals: intervals.to_vec(),
+            bins: intervals.iter().map(|_| AtomicU64::new(0)).collect(),
+        }
+    }
+
+    fn add(&self, value: u64, count: u64) {
+        // index of the last bin which starts at or before value
+        let index = self.intervals.partition_point(|start| *start <= value);
+        if index > 0 {
+            self.bins[index - 1].fetch_add(count, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    fn reset(&self) {
+        for bin in &self.bins {
+            bin.store(0, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    fn bins(&self) -> Vec<HistogramBin> {
+        self.intervals
+            .iter()
+            .enumerate()
+            .map(|(i, start)| HistogramBin {
+                start: *start,
+                end: self.intervals.get(i + 1).copied().unwrap_or(u64::MAX),
+                count: self.bins[i].load(std::sync::atomic::Ordering::SeqCst),
+            })
+            .collect()
+    }
+}
+
+const MESSAGE_TYPES: [MessageType; 15] = [
+    MessageType::Invalid,
+    MessageType::NotAType,
+    MessageType::Keepalive,
+    MessageType::Publish,
+    MessageType::ConfirmReq,
+    MessageType::ConfirmAck,
+    MessageType::BulkPull,
+    MessageType::BulkPush,
+    MessageType::FrontierReq,
+    MessageType::NodeIdHandshake,
+    MessageType::BulkPullAccount,
+    MessageType::TelemetryReq,
+    MessageType::TelemetryAck,
+    MessageType::AscPullReq,
+    MessageType::AscPullAck,
+];
+
 impl From<MessageType> for DetailType {
     fn from(msg: MessageType) -> Self {
         match msg {
@@ -453,6 +765,10 @@ impl StatsLoop {
                 .0;
 
             if !guard.stopped {
+                self.mutables
+                    .read()
+                    .unwrap()
+                    .record_rate_datapoints(Instant::now());
                 self.run_one(&mut guard).unwrap();
             }
         }
@@ -468,12 +784,12 @@ impl StatsLoop {
             let writer = match log_count.as_mut() {
                 Some(x) => x,
                 None => {
-                    let writer = StatFileWriter::new(&self.config.log_counters_filename)?;
+                    let writer = self.create_log_sink(&self.config.log_counters_filename)?;
                     log_count.get_or_insert(writer)
                 }
             };
 
-            stats.log_counters_impl(writer, &self.config, SystemTime::now())?;
+            stats.log_counters_impl(writer.as_mut(), &self.config, SystemTime::now())?;
             lock.log_last_count_writeout = Instant::now();
         }
 
@@ -485,30 +801,41 @@ impl StatsLoop {
             let writer = match log_sample.as_mut() {
                 Some(x) => x,
                 None => {
-                    let writer = StatFileWriter::new(&self.config.log_samples_filename)?;
+                    let writer = self.create_log_sink(&self.config.log_samples_filename)?;
                     log_sample.get_or_insert(writer)
                 }
             };
-            stats.log_samples_impl(writer, &self.config, SystemTime::now())?;
+            stats.log_samples_impl(writer.as_mut(), &self.config, SystemTime::now())?;
             lock.log_last_sample_writeout = Instant::now();
         }
 
         Ok(())
     }
+
+    fn create_log_sink(&self, filename: &str) -> anyhow::Result<Box<dyn StatsLogSink + Send>> {
+        Ok(match self.config.log_format {
+            StatsLogFormat::Text => Box::new(StatFileWriter::new(filename)?),
+            StatsLogFormat::Csv => Box::new(StatCsvWriter::new(filename)?),
+        })
+    }
 }
 
 struct StatsLoopState {
+    started: bool,
     stopped: bool,
     log_last_count_writeout: Instant,
     log_last_sample_writeout: Instant,
 }
 
-static LOG_COUNT: Lazy<Mutex<Option<StatFileWriter>>> = Lazy::new(|| Mutex::new(None));
-static LOG_SAMPLE: Lazy<Mutex<Option<StatFileWriter>>> = Lazy::new(|| Mutex::new(None));
+static LOG_COUNT: Lazy<Mutex<Option<Box<dyn StatsLogSink + Send>>>> =
+    Lazy::new(|| Mutex::new(None));
+static LOG_SAMPLE: Lazy<Mutex<Option<Box<dyn StatsLogSink + Send>>>> =
+    Lazy::new(|| Mutex::new(None));
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use test_helpers::assert_timely;
 
     /// Test stat counting at both type and detail levels
     #[test]
@@ -558,4 +885,316 @@ mod tests {
         let samples4 = stats.samples(Sample::BootstrapTagDuration);
         assert_eq!(samples4, [2137]);
     }
+
+    #[test]
+    fn rate() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.enable_rate_sampling(StatType::Ledger, DetailType::Send, Direction::In);
+        let start = Instant::now();
+        for i in 0..5 {
+            stats.add(StatType::Ledger, DetailType::Send, 10);
+            stats.record_rate_datapoints(start + Duration::from_secs(i));
+        }
+
+        let rate = stats.rate(
+            StatType::Ledger,
+            DetailType::Send,
+            Direction::In,
+            Duration::from_secs(2),
+        );
+
+        assert!((rate - 10.0).abs() < 0.001, "rate was {}", rate);
+    }
+
+    #[test]
+    fn enable_rate_sampling_after_start() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.start();
+        stats.enable_rate_sampling(StatType::Ledger, DetailType::Send, Direction::In);
+
+        assert_timely(Duration::from_secs(10), || {
+            stats.add(StatType::Ledger, DetailType::Send, 10);
+            stats.rate(
+                StatType::Ledger,
+                DetailType::Send,
+                Direction::In,
+                Duration::from_secs(10),
+            ) > 0.0
+        });
+        stats.stop();
+    }
+
+    #[test]
+    fn enable_rate_sampling_all() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.enable_rate_sampling_all(&[
+            (StatType::Ledger, DetailType::Send, Direction::In, 0, 2),
+            (
+                StatType::Ledger,
+                DetailType::Receive,
+                Direction::In,
+                1000,
+                10,
+            ),
+        ]);
+
+        let start = Instant::now();
+        for i in 0..4 {
+            stats.add(StatType::Ledger, DetailType::Send, 1);
+            stats.add(StatType::Ledger, DetailType::Receive, 1);
+            stats.record_rate_datapoints(start + Duration::from_millis(i * 600));
+        }
+
+        let datapoints = |detail| -> Vec<u64> {
+            let lock = stats.mutables.read().unwrap();
+            let key = CounterKey::new(StatType::Ledger, detail, Direction::In);
+            let sampler = lock.rate_samplers.get(&key).unwrap();
+            let values = sampler.datapoints.lock().unwrap();
+            values.iter().map(|(_, value)| *value).collect()
+        };
+        // no minimum interval, but only the last 2 datapoints are kept
+        assert_eq!(datapoints(DetailType::Send), vec![3, 4]);
+        // datapoints at 0ms and 1200ms, the ones in between are skipped
+        assert_eq!(datapoints(DetailType::Receive), vec![1, 3]);
+    }
+
+    #[test]
+    fn enable_rate_sampling_all_again_changes_settings() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.enable_rate_sampling_all(&[(
+            StatType::Ledger,
+            DetailType::Send,
+            Direction::In,
+            0,
+            2,
+        )]);
+        stats.enable_rate_sampling_all(&[(
+            StatType::Ledger,
+            DetailType::Send,
+            Direction::In,
+            0,
+            3,
+        )]);
+
+        let start = Instant::now();
+        for i in 0..4 {
+            stats.add(StatType::Ledger, DetailType::Send, 1);
+            stats.record_rate_datapoints(start + Duration::from_millis(i * 600));
+        }
+
+        let lock = stats.mutables.read().unwrap();
+        let key = CounterKey::new(StatType::Ledger, DetailType::Send, Direction::In);
+        let datapoints = lock
+            .rate_samplers
+            .get(&key)
+            .unwrap()
+            .datapoints
+            .lock()
+            .unwrap();
+        assert_eq!(
+            datapoints
+                .iter()
+                .map(|(_, value)| *value)
+                .collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn rate_without_enough_datapoints() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.enable_rate_sampling(StatType::Ledger, DetailType::Send, Direction::In);
+        let start = Instant::now();
+        stats.add(StatType::Ledger, DetailType::Send, 10);
+        stats.record_rate_datapoints(start);
+        stats.add(StatType::Ledger, DetailType::Send, 10);
+        stats.record_rate_datapoints(start + Duration::from_secs(10));
+
+        let rate = stats.rate(
+            StatType::Ledger,
+            DetailType::Send,
+            Direction::In,
+            Duration::from_secs(5),
+        );
+
+        assert_eq!(rate, 0.0);
+    }
+
+    #[test]
+    fn count_by_type_sums_details() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.add(StatType::Ledger, DetailType::Send, 2);
+        stats.add(StatType::Ledger, DetailType::Receive, 3);
+        stats.inc_dir(StatType::Ledger, DetailType::Send, Direction::Out);
+
+        assert_eq!(stats.count_by_type(StatType::Ledger, Direction::In), 5);
+        assert_eq!(stats.count_by_type(StatType::Ledger, Direction::Out), 1);
+        assert_eq!(stats.count_by_type(StatType::Vote, Direction::In), 0);
+    }
+
+    #[test]
+    fn count_by_type_uses_aggregate() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.add_dir_aggregate(StatType::Ledger, DetailType::Send, Direction::In, 2);
+        stats.add_dir_aggregate(StatType::Ledger, DetailType::Receive, Direction::In, 3);
+
+        assert_eq!(stats.count_by_type(StatType::Ledger, Direction::In), 5);
+    }
+
+    #[test]
+    fn message_counts() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.inc_dir(StatType::Message, DetailType::Keepalive, Direction::In);
+        stats.inc_dir(StatType::Message, DetailType::Keepalive, Direction::In);
+        stats.inc_dir(StatType::Message, DetailType::ConfirmAck, Direction::In);
+        stats.inc_dir(StatType::Message, DetailType::Publish, Direction::Out);
+
+        let counts = stats.message_counts(Direction::In);
+
+        assert_eq!(counts.len(), MESSAGE_TYPES.len());
+        assert_eq!(counts[&MessageType::Keepalive], 2);
+        assert_eq!(counts[&MessageType::ConfirmAck], 1);
+        assert_eq!(counts[&MessageType::Publish], 0);
+        assert_eq!(
+            stats.message_counts(Direction::Out)[&MessageType::Publish],
+            1
+        );
+    }
+
+    #[test]
+    fn clear_one() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.inc_dir_aggregate(StatType::Ledger, DetailType::Send, Direction::In);
+        stats.inc_dir_aggregate(StatType::Ledger, DetailType::Receive, Direction::In);
+
+        stats.clear_one(StatType::Ledger, DetailType::Send, Direction::In);
+
+        assert_eq!(
+            stats.count(StatType::Ledger, DetailType::Send, Direction::In),
+            0
+        );
+        assert_eq!(
+            stats.count(StatType::Ledger, DetailType::Receive, Direction::In),
+            1
+        );
+        assert_eq!(
+            stats.count(StatType::Ledger, DetailType::All, Direction::In),
+            2
+        );
+
+        stats.clear_one(StatType::Ledger, DetailType::Receive, Direction::In);
+
+        assert_eq!(
+            stats.count(StatType::Ledger, DetailType::All, Direction::In),
+            0
+        );
+        assert!(stats.snapshot().is_empty());
+    }
+
+    #[test]
+    fn snapshot() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.inc(StatType::Ledger, DetailType::Send);
+        stats.inc(StatType::Ledger, DetailType::Send);
+        stats.add_dir(StatType::Vote, DetailType::Valid, Direction::Out, 5);
+
+        let snapshot = stats.snapshot();
+
+        let values: Vec<_> = snapshot
+            .iter()
+            .map(|s| (s.stat_type, s.detail, s.dir, s.value))
+            .collect();
+        assert_eq!(
+            values,
+            [
+                (StatType::Ledger, DetailType::All, Direction::In, 0),
+                (StatType::Ledger, DetailType::Send, Direction::In, 2),
+                (StatType::Vote, DetailType::All, Direction::Out, 0),
+                (StatType::Vote, DetailType::Valid, Direction::Out, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn histogram() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.define_histogram(
+            StatType::Bootstrap,
+            DetailType::ReplyLatency,
+            Direction::In,
+            &[1, 10, 100],
+        );
+
+        stats.update_histogram(
+            StatType::Bootstrap,
+            DetailType::ReplyLatency,
+            Direction::In,
+            0,
+            1,
+        );
+        stats.update_histogram(
+            StatType::Bootstrap,
+            DetailType::ReplyLatency,
+            Direction::In,
+            1,
+            1,
+        );
+        stats.update_histogram(
+            StatType::Bootstrap,
+            DetailType::ReplyLatency,
+            Direction::In,
+            9,
+            2,
+        );
+        stats.update_histogram(
+            StatType::Bootstrap,
+            DetailType::ReplyLatency,
+            Direction::In,
+            50,
+            1,
+        );
+        stats.update_histogram(
+            StatType::Bootstrap,
+            DetailType::ReplyLatency,
+            Direction::In,
+            5000,
+            1,
+        );
+
+        assert_eq!(
+            stats.histogram(StatType::Bootstrap, DetailType::ReplyLatency, Direction::In),
+            vec![
+                HistogramBin {
+                    start: 1,
+                    end: 10,
+                    count: 3
+                },
+                HistogramBin {
+                    start: 10,
+                    end: 100,
+                    count: 1
+                },
+                HistogramBin {
+                    start: 100,
+                    end: u64::MAX,
+                    count: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn undefined_histogram() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.update_histogram(
+            StatType::Bootstrap,
+            DetailType::ReplyLatency,
+            Direction::In,
+            1,
+            1,
+        );
+        assert!(stats
+            .histogram(StatType::Bootstrap, DetailType::ReplyLatency, Direction::In)
+            .is_empty());
+    }
 }
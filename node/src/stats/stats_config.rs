@@ -1,5 +1,30 @@
 use std::time::Duration;
 
+/// Output format of the counter and sample log files
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum StatsLogFormat {
+    #[default]
+    Text,
+    Csv,
+}
+
+impl StatsLogFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatsLogFormat::Text => "text",
+            StatsLogFormat::Csv => "csv",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(StatsLogFormat::Text),
+            "csv" => Some(StatsLogFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct StatsConfig {
     /** How many sample intervals to keep in the ring buffer */
@@ -22,6 +47,9 @@ pub struct StatsConfig {
 
     /** Filename for the sampling log */
     pub log_samples_filename: String,
+
+    /** Format of the counter and sampling log files */
+    pub log_format: StatsLogFormat,
 }
 
 impl Default for StatsConfig {
@@ -34,6 +62,7 @@ impl Default for StatsConfig {
             log_headers: true,
             log_counters_filename: "counters.stat".to_string(),
             log_samples_filename: "samples.stat".to_string(),
+            log_format: StatsLogFormat::Text,
         }
     }
 }
@@ -57,6 +57,7 @@ pub enum StatType {
     BootstrapProcess,
     BootstrapRequest,
     BootstrapRequestBlocks,
+    BootstrapRequestSource,
     BootstrapReply,
     BootstrapNext,
     BootstrapFrontiers,
@@ -336,6 +337,7 @@ pub enum DetailType {
     HandshakeInitiate,
     HandshakeResponse,
     HandshakeResponseInvalid,
+    HandshakeTimeout,
 
     // ipc
     Invocations,
@@ -435,6 +437,7 @@ pub enum DetailType {
     // bootstrap
     MissingTag,
     Reply,
+    ReplyLatency,
     Throttled,
     Track,
     Timeout,
@@ -452,6 +455,7 @@ pub enum DetailType {
     ProcessingFrontiers,
     FrontiersDropped,
     SyncAccounts,
+    Paused,
 
     Prioritize,
     PrioritizeFailed,
@@ -552,6 +556,11 @@ pub enum DetailType {
     BlocksByHash,
     BlocksByAccount,
     AccountInfoByHash,
+    AccountInfoByAccount,
+
+    // query_source
+    Database,
+    Dependencies,
 }
 
 impl DetailType {
@@ -134,6 +134,128 @@ impl StatsLogSink for StatFileWriter {
     }
 }
 
+/// CSV sink with rotation support. Starts with a header row and writes one row per counter.
+/// Samples are written as one row per value with an empty detail and direction.
+pub struct StatCsvWriter {
+    target: CsvTarget,
+    header_written: bool,
+    log_entries: usize,
+}
+
+enum CsvTarget {
+    File { filename: PathBuf, file: File },
+    Memory(Vec<u8>),
+}
+
+impl StatCsvWriter {
+    pub const HEADER: &'static str = "time,type,detail,dir,value";
+
+    pub fn new(filename: impl Into<PathBuf>) -> Result<Self> {
+        let filename = filename.into();
+        let file = File::create(filename.clone())?;
+        Ok(Self::with_target(CsvTarget::File { filename, file }))
+    }
+
+    /// Keeps the CSV output in memory. It can be read with `to_string()`
+    pub fn new_in_memory() -> Self {
+        Self::with_target(CsvTarget::Memory(Vec::new()))
+    }
+
+    fn with_target(target: CsvTarget) -> Self {
+        Self {
+            target,
+            header_written: false,
+            log_entries: 0,
+        }
+    }
+
+    fn writer(&mut self) -> &mut dyn Write {
+        match &mut self.target {
+            CsvTarget::File { file, .. } => file,
+            CsvTarget::Memory(buffer) => buffer,
+        }
+    }
+
+    fn write_row(&mut self, row: &str) -> Result<()> {
+        if !self.header_written {
+            writeln!(self.writer(), "{}", Self::HEADER)?;
+            self.header_written = true;
+        }
+        writeln!(self.writer(), "{}", row)?;
+        Ok(())
+    }
+}
+
+impl StatsLogSink for StatCsvWriter {
+    fn begin(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn finalize(&mut self) {
+        let _ = self.writer().flush();
+    }
+
+    /// The CSV columns are fixed, so there is no place for a header entry
+    fn write_header(&mut self, _header: &str, _walltime: SystemTime) -> Result<()> {
+        Ok(())
+    }
+
+    fn write_counter_entry(
+        &mut self,
+        time: SystemTime,
+        entry_type: &str,
+        detail: &str,
+        dir: &str,
+        value: u64,
+    ) -> Result<()> {
+        let now = DateTime::<Local>::from(time).format("%H:%M:%S");
+        self.write_row(&format!("{now},{entry_type},{detail},{dir},{value}"))
+    }
+
+    fn write_sampler_entry(
+        &mut self,
+        time: SystemTime,
+        sample: &str,
+        values: Vec<i64>,
+        _expected_min_max: (i64, i64),
+    ) -> Result<()> {
+        let now = DateTime::<Local>::from(time).format("%H:%M:%S");
+        for value in values {
+            self.write_row(&format!("{now},{sample},,,{value}"))?;
+        }
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        match &mut self.target {
+            CsvTarget::File { filename, file } => *file = File::create(filename.clone())?,
+            CsvTarget::Memory(buffer) => buffer.clear(),
+        }
+        self.header_written = false;
+        self.log_entries = 0;
+        Ok(())
+    }
+
+    fn entries(&self) -> usize {
+        self.log_entries
+    }
+
+    fn inc_entries(&mut self) {
+        self.log_entries += 1;
+    }
+
+    fn to_string(&self) -> String {
+        match &self.target {
+            CsvTarget::File { .. } => String::new(),
+            CsvTarget::Memory(buffer) => String::from_utf8_lossy(buffer).into_owned(),
+        }
+    }
+
+    fn to_object(&self) -> Option<&dyn Any> {
+        None
+    }
+}
+
 pub struct StatsJsonWriterV2 {
     tree: serde_json::Map<String, serde_json::Value>,
     entries: Vec<serde_json::Value>,
@@ -276,3 +398,48 @@ impl StatsLogSink for StatsJsonWriterV2 {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::{DetailType, Direction, StatType, Stats, StatsConfig};
+
+    #[test]
+    fn csv_counters() {
+        let stats = Stats::new(StatsConfig::new());
+        stats.add(StatType::Ledger, DetailType::Send, 3);
+        stats.inc_dir(StatType::Ledger, DetailType::Receive, Direction::Out);
+        let mut sink = StatCsvWriter::new_in_memory();
+
+        stats.log_counters(&mut sink).unwrap();
+
+        let csv = sink.to_string();
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines[0], StatCsvWriter::HEADER);
+        assert!(lines.iter().any(|l| l.ends_with(",ledger,send,in,3")));
+        assert!(lines.iter().any(|l| l.ends_with(",ledger,receive,out,1")));
+        assert_eq!(sink.entries(), 1);
+    }
+
+    #[test]
+    fn csv_rotation() {
+        let stats = Stats::new(StatsConfig {
+            log_rotation_count: 2,
+            ..StatsConfig::new()
+        });
+        stats.add(StatType::Ledger, DetailType::Send, 3);
+        let mut sink = StatCsvWriter::new_in_memory();
+
+        for _ in 0..3 {
+            stats.log_counters(&mut sink).unwrap();
+        }
+
+        let csv = sink.to_string();
+        assert_eq!(
+            csv.lines().filter(|l| *l == StatCsvWriter::HEADER).count(),
+            1
+        );
+        assert_eq!(csv.lines().filter(|l| l.contains(",send,")).count(), 1);
+        assert_eq!(sink.entries(), 1);
+    }
+}
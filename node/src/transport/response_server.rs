@@ -28,6 +28,7 @@ pub struct TcpConfig {
     pub max_attempts: usize,
     pub max_attempts_per_ip: usize,
     pub connect_timeout: Duration,
+    pub handshake_timeout: Duration,
 }
 
 impl TcpConfig {
@@ -38,6 +39,7 @@ impl TcpConfig {
             max_attempts: 128,
             max_attempts_per_ip: 128,
             connect_timeout: Duration::from_secs(5),
+            handshake_timeout: Duration::from_secs(5),
         }
     }
 }
@@ -50,6 +52,7 @@ impl Default for TcpConfig {
             max_attempts: 60,
             max_attempts_per_ip: 1,
             connect_timeout: Duration::from_secs(60),
+            handshake_timeout: Duration::from_secs(10),
         }
     }
 }
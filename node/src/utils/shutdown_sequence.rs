@@ -0,0 +1,149 @@
+use std::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShutdownOutcome {
+    Stopped,
+    TimedOut,
+}
+
+/// Stops components one after another in the order they were added.
+/// Each component gets at most `timeout` to stop, so that a wedged
+/// component cannot block the whole shutdown.
+pub struct ShutdownSequence {
+    timeout: Duration,
+    steps: Vec<(&'static str, Box<dyn FnOnce() + Send>)>,
+}
+
+impl ShutdownSequence {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, name: &'static str, stop: impl FnOnce() + Send + 'static) {
+        self.steps.push((name, Box::new(stop)));
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn run(self) -> Vec<(&'static str, ShutdownOutcome)> {
+        let mut results = Vec::with_capacity(self.steps.len());
+        for (name, stop) in self.steps {
+            let outcome = run_with_timeout(name, stop, self.timeout);
+            results.push((name, outcome));
+        }
+        results
+    }
+}
+
+fn run_with_timeout(
+    name: &'static str,
+    stop: Box<dyn FnOnce() + Send>,
+    timeout: Duration,
+) -> ShutdownOutcome {
+    info!("Stopping {}...", name);
+    let start = Instant::now();
+    let (tx, rx) = mpsc::channel();
+    let spawned = std::thread::Builder::new()
+        .name(format!("Stop {}", name))
+        .spawn(move || {
+            stop();
+            let _ = tx.send(());
+        });
+
+    if let Err(e) = spawned {
+        warn!("Could not spawn shutdown thread for {}: {:?}", name, e);
+        return ShutdownOutcome::TimedOut;
+    }
+
+    match rx.recv_timeout(timeout) {
+        Ok(()) => {
+            info!("Stopped {} in {} ms", name, start.elapsed().as_millis());
+            ShutdownOutcome::Stopped
+        }
+        Err(_) => {
+            warn!(
+                "Stopping {} did not finish within {} ms, continuing shutdown",
+                name,
+                timeout.as_millis()
+            );
+            ShutdownOutcome::TimedOut
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn empty() {
+        let sequence = ShutdownSequence::new(Duration::from_secs(1));
+        assert!(sequence.is_empty());
+        assert_eq!(sequence.run(), Vec::new());
+    }
+
+    #[test]
+    fn stops_in_order() {
+        let stopped = Arc::new(Mutex::new(Vec::new()));
+        let mut sequence = ShutdownSequence::new(Duration::from_secs(5));
+        for name in ["wallets", "schedulers", "bootstrap"] {
+            let stopped = stopped.clone();
+            sequence.add(name, move || stopped.lock().unwrap().push(name));
+        }
+
+        let results = sequence.run();
+
+        assert_eq!(
+            *stopped.lock().unwrap(),
+            vec!["wallets", "schedulers", "bootstrap"]
+        );
+        assert_eq!(
+            results,
+            vec![
+                ("wallets", ShutdownOutcome::Stopped),
+                ("schedulers", ShutdownOutcome::Stopped),
+                ("bootstrap", ShutdownOutcome::Stopped),
+            ]
+        );
+    }
+
+    #[test]
+    fn wedged_component_times_out() {
+        let (_release_tx, release_rx) = mpsc::channel::<()>();
+        let stopped = Arc::new(Mutex::new(Vec::new()));
+        let mut sequence = ShutdownSequence::new(Duration::from_millis(50));
+        sequence.add("wedged", move || {
+            // blocks until the test ends
+            let _ = release_rx.recv();
+        });
+        let stopped2 = stopped.clone();
+        sequence.add("after", move || stopped2.lock().unwrap().push("after"));
+
+        let start = Instant::now();
+        let results = sequence.run();
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(
+            results,
+            vec![
+                ("wedged", ShutdownOutcome::TimedOut),
+                ("after", ShutdownOutcome::Stopped),
+            ]
+        );
+        assert_eq!(*stopped.lock().unwrap(), vec!["after"]);
+    }
+}
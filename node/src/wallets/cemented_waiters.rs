@@ -0,0 +1,126 @@
+use rsnano_core::BlockHash;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Condvar, Mutex},
+    time::Duration,
+};
+
+/// Lets threads block until a given block gets cemented
+#[derive(Default)]
+pub(crate) struct CementedWaiters {
+    state: Mutex<WaitersState>,
+    condition: Condvar,
+}
+
+#[derive(Default)]
+struct WaitersState {
+    /// Number of threads waiting for each block
+    waiting: HashMap<BlockHash, usize>,
+    /// Blocks that got cemented while somebody was waiting for them
+    cemented: HashSet<BlockHash>,
+}
+
+impl CementedWaiters {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Wakes up everybody who is waiting for the given block to be cemented
+    pub fn block_cemented(&self, hash: &BlockHash) {
+        let mut state = self.state.lock().unwrap();
+        if state.waiting.contains_key(hash) {
+            state.cemented.insert(*hash);
+            self.condition.notify_all();
+        }
+    }
+
+    /// Blocks until the block is cemented or the timeout elapsed.
+    /// `is_cemented` is checked under the lock, so that a block which gets
+    /// cemented right before the wait starts isn't missed.
+    /// Returns true if the block got cemented
+    pub fn wait(
+        &self,
+        hash: &BlockHash,
+        timeout: Duration,
+        is_cemented: impl FnOnce() -> bool,
+    ) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if is_cemented() {
+            return true;
+        }
+        *state.waiting.entry(*hash).or_default() += 1;
+
+        let (mut state, _) = self
+            .condition
+            .wait_timeout_while(state, timeout, |s| !s.cemented.contains(hash))
+            .unwrap();
+
+        let cemented = state.cemented.contains(hash);
+        let count = state.waiting.get_mut(hash).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            state.waiting.remove(hash);
+            state.cemented.remove(hash);
+        }
+        cemented
+    }
+
+    #[cfg(test)]
+    fn is_empty(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.waiting.is_empty() && state.cemented.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn already_cemented() {
+        let waiters = CementedWaiters::new();
+        assert!(waiters.wait(&BlockHash::from(1), Duration::ZERO, || true));
+        assert!(waiters.is_empty());
+    }
+
+    #[test]
+    fn timeout() {
+        let waiters = CementedWaiters::new();
+        assert_eq!(
+            waiters.wait(&BlockHash::from(1), Duration::from_millis(10), || false),
+            false
+        );
+        assert!(waiters.is_empty());
+    }
+
+    #[test]
+    fn ignore_cemented_block_nobody_waits_for() {
+        let waiters = CementedWaiters::new();
+        waiters.block_cemented(&BlockHash::from(1));
+        assert!(waiters.is_empty());
+    }
+
+    #[test]
+    fn wake_up_all_waiters_of_same_block() {
+        let waiters = CementedWaiters::new();
+        let hash = BlockHash::from(1);
+
+        thread::scope(|s| {
+            let handles: Vec<_> = (0..2)
+                .map(|_| s.spawn(|| waiters.wait(&hash, Duration::from_secs(10), || false)))
+                .collect();
+
+            while waiters.state.lock().unwrap().waiting.get(&hash) != Some(&2) {
+                thread::yield_now();
+            }
+            waiters.block_cemented(&hash);
+
+            for handle in handles {
+                assert!(handle.join().unwrap());
+            }
+        });
+
+        assert!(waiters.is_empty());
+    }
+}
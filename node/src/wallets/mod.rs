@@ -1,3 +1,4 @@
+mod cemented_waiters;
 mod wallet;
 mod wallet_action_thread;
 mod wallet_representatives;
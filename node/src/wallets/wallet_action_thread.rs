@@ -66,6 +66,11 @@ impl WalletActionThread {
         self.action_loop.len()
     }
 
+    /// Gets set when the wallet action thread is stopped
+    pub fn stop_flag(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.action_loop.stopped)
+    }
+
     pub fn set_observer(&self, observer: Box<dyn Fn(bool) + Send>) {
         self.action_loop.set_observer(observer);
     }
@@ -93,7 +98,7 @@ impl WalletActionThread {
 
 struct WalletActionLoop {
     mutex: Mutex<BTreeMap<Amount, Vec<(Arc<Wallet>, Box<dyn Fn(Arc<Wallet>) + Send>)>>>,
-    stopped: AtomicBool,
+    stopped: Arc<AtomicBool>,
     condition: Condvar,
     observer: Mutex<Box<dyn Fn(bool) + Send>>,
 }
@@ -102,7 +107,7 @@ impl WalletActionLoop {
     fn new() -> Self {
         Self {
             mutex: Mutex::new(BTreeMap::new()),
-            stopped: AtomicBool::new(false),
+            stopped: Arc::new(AtomicBool::new(false)),
             condition: Condvar::new(),
             observer: Mutex::new(Box::new(|_| {})),
         }
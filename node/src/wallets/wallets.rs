@@ -1,4 +1,4 @@
-use super::{Wallet, WalletActionThread, WalletRepresentatives};
+use super::{cemented_waiters::CementedWaiters, Wallet, WalletActionThread, WalletRepresentatives};
 use crate::{
     block_processing::{BlockProcessor, BlockSource},
     cementation::{ConfirmingSet, ConfirmingSetConfig},
@@ -10,6 +10,7 @@ use crate::{
     work::DistributedWorkFactory,
     NetworkParams,
 };
+use anyhow::Context;
 use rand::{thread_rng, Rng};
 use rsnano_core::{
     utils::{get_env_or_default_string, ContainerInfo},
@@ -23,7 +24,7 @@ use rsnano_network::DropPolicy;
 use rsnano_nullable_lmdb::{DatabaseFlags, LmdbDatabase, WriteFlags};
 use rsnano_store_lmdb::{
     create_backup_file, KeyType, LmdbEnv, LmdbIterator, LmdbWalletStore, LmdbWriteTransaction,
-    Transaction,
+    RekeyError, Transaction,
 };
 use serde::{Deserialize, Serialize};
 use std::{
@@ -33,7 +34,10 @@ use std::{
     mem::size_of,
     os::unix::fs::PermissionsExt,
     path::{Path, PathBuf},
-    sync::{Arc, Condvar, Mutex},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex,
+    },
     time::{Duration, Instant},
 };
 use tracing::{info, warn};
@@ -47,6 +51,8 @@ pub enum WalletsError {
     AccountNotFound,
     InvalidPassword,
     BadPublicKey,
+    Io,
+    KdfFailure,
 }
 
 impl WalletsError {
@@ -59,6 +65,8 @@ impl WalletsError {
             WalletsError::AccountNotFound => "Account not found",
             WalletsError::InvalidPassword => "Invalid password",
             WalletsError::BadPublicKey => "Bad public key",
+            WalletsError::Io => "IO error",
+            WalletsError::KdfFailure => "Key derivation failed",
         }
     }
 }
@@ -71,6 +79,16 @@ impl fmt::Display for WalletsError {
 
 impl std::error::Error for WalletsError {}
 
+impl From<RekeyError> for WalletsError {
+    fn from(value: RekeyError) -> Self {
+        match value {
+            RekeyError::InvalidPassword => WalletsError::WalletLocked,
+            RekeyError::KdfFailure => WalletsError::KdfFailure,
+            RekeyError::Io => WalletsError::Io,
+        }
+    }
+}
+
 pub enum PreparedSend {
     Cached(SavedBlock),
     New(Block, BlockDetails),
@@ -79,6 +97,8 @@ pub enum PreparedSend {
 pub struct Wallets {
     db: Option<LmdbDatabase>,
     send_action_ids_handle: Option<LmdbDatabase>,
+    /// Local only account labels, keyed by wallet id and account
+    labels_handle: Option<LmdbDatabase>,
     env: Arc<LmdbEnv>,
     pub mutex: Mutex<HashMap<WalletId, Arc<Wallet>>>,
     node_config: NodeConfig,
@@ -97,6 +117,7 @@ pub struct Wallets {
     start_election: Mutex<Option<Box<dyn Fn(SavedBlock) + Send + Sync>>>,
     confirming_set: Arc<ConfirmingSet>,
     message_flooder: Mutex<MessageFlooder>,
+    cemented_waiters: Arc<CementedWaiters>,
 }
 
 impl Wallets {
@@ -143,9 +164,15 @@ impl Wallets {
         message_flooder: MessageFlooder,
     ) -> Self {
         let kdf = KeyDerivationFunction::new(kdf_work);
+        let cemented_waiters = Arc::new(CementedWaiters::new());
+        let waiters = Arc::clone(&cemented_waiters);
+        confirming_set.on_cemented(Box::new(move |block| {
+            waiters.block_cemented(&block.hash());
+        }));
         Self {
             db: None,
             send_action_ids_handle: None,
+            labels_handle: None,
             mutex: Mutex::new(HashMap::new()),
             env,
             node_config: node_config.clone(),
@@ -167,6 +194,7 @@ impl Wallets {
             start_election: Mutex::new(None),
             confirming_set,
             message_flooder: Mutex::new(message_flooder),
+            cemented_waiters,
         }
     }
 
@@ -182,6 +210,15 @@ impl Wallets {
         *self.start_election.lock().unwrap() = Some(callback);
     }
 
+    /// Blocks until the block is cemented or the timeout elapsed.
+    /// Returns true if the block got cemented
+    fn wait_cemented(&self, hash: &BlockHash, timeout: Duration) -> bool {
+        self.cemented_waiters.wait(hash, timeout, || {
+            let tx = self.ledger.read_txn();
+            self.ledger.confirmed().block_exists(&tx, hash)
+        })
+    }
+
     pub fn initialize(&mut self) -> anyhow::Result<()> {
         let mut txn = self.env.tx_begin_write();
         self.db = Some(unsafe { txn.rw_txn_mut().create_db(None, DatabaseFlags::empty())? });
@@ -189,6 +226,10 @@ impl Wallets {
             txn.rw_txn_mut()
                 .create_db(Some("send_action_ids"), DatabaseFlags::empty())?
         });
+        self.labels_handle = Some(unsafe {
+            txn.rw_txn_mut()
+                .create_db(Some("account_labels"), DatabaseFlags::empty())?
+        });
         {
             let mut guard = self.mutex.lock().unwrap();
             let wallet_ids = self.get_wallet_ids(&txn);
@@ -286,6 +327,76 @@ impl Wallets {
         Ok(())
     }
 
+    pub fn set_account_label(
+        &self,
+        wallet_id: &WalletId,
+        account: &Account,
+        label: &str,
+    ) -> Result<(), WalletsError> {
+        let guard = self.mutex.lock().unwrap();
+        let wallet = Self::get_wallet(&guard, wallet_id)?;
+        let mut tx = self.env.tx_begin_write();
+        if wallet.store.find(&tx, &account.into()).is_none() {
+            return Err(WalletsError::AccountNotFound);
+        }
+        tx.rw_txn_mut()
+            .put(
+                self.labels_handle.unwrap(),
+                &Self::label_key(wallet_id, account),
+                label.as_bytes(),
+                WriteFlags::empty(),
+            )
+            .map_err(|_| WalletsError::Generic)
+    }
+
+    pub fn get_account_label(&self, wallet_id: &WalletId, account: &Account) -> Option<String> {
+        let guard = self.mutex.lock().unwrap();
+        Self::get_wallet(&guard, wallet_id).ok()?;
+        let tx = self.env.tx_begin_read();
+        let bytes = tx
+            .get(
+                self.labels_handle.unwrap(),
+                &Self::label_key(wallet_id, account),
+            )
+            .ok()?;
+        Some(String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    pub fn clear_account_label(
+        &self,
+        wallet_id: &WalletId,
+        account: &Account,
+    ) -> Result<(), WalletsError> {
+        let guard = self.mutex.lock().unwrap();
+        Self::get_wallet(&guard, wallet_id)?;
+        let mut tx = self.env.tx_begin_write();
+        self.delete_account_label(&mut tx, wallet_id, account)
+            .map_err(|_| WalletsError::Generic)
+    }
+
+    fn delete_account_label(
+        &self,
+        tx: &mut LmdbWriteTransaction,
+        wallet_id: &WalletId,
+        account: &Account,
+    ) -> Result<(), rsnano_nullable_lmdb::Error> {
+        match tx.delete(
+            self.labels_handle.unwrap(),
+            &Self::label_key(wallet_id, account),
+            None,
+        ) {
+            Ok(()) | Err(rsnano_nullable_lmdb::Error::NotFound) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn label_key(wallet_id: &WalletId, account: &Account) -> [u8; 64] {
+        let mut key = [0; 64];
+        key[..32].copy_from_slice(wallet_id.as_bytes());
+        key[32..].copy_from_slice(account.as_bytes());
+        key
+    }
+
     pub fn clear_send_ids(&self) {
         let mut tx = self.env.tx_begin_write();
         tx.clear_db(self.send_action_ids_handle.unwrap()).unwrap();
@@ -382,10 +493,6 @@ impl Wallets {
         let guard = self.mutex.lock().unwrap();
         let wallet = Self::get_wallet(&guard, wallet_id)?;
         let mut tx = self.env.tx_begin_write();
-        if !wallet.store.valid_password(&tx) {
-            return Err(WalletsError::WalletLocked);
-        }
-
         for account in accounts {
             if wallet.store.insert_watch(&mut tx, &account.into()).is_err() {
                 return Err(WalletsError::BadPublicKey);
@@ -436,10 +543,8 @@ impl Wallets {
             return Err(WalletsError::WalletLocked);
         }
 
-        wallet
-            .store
-            .rekey(&mut tx, password.as_ref())
-            .map_err(|_| WalletsError::Generic)
+        wallet.store.rekey(&mut tx, password.as_ref())?;
+        Ok(())
     }
 
     pub fn set_observer(&self, observer: Box<dyn Fn(bool) + Send>) {
@@ -520,6 +625,9 @@ impl Wallets {
         // action_mutex should be locked after transactions to prevent deadlocks in deterministic_insert () & insert_adhoc ()
         let _action_guard = self.wallet_actions.lock_safe();
         let wallet = guard.remove(id).unwrap();
+        for account in wallet.store.accounts(&tx) {
+            self.delete_account_label(&mut tx, id, &account).unwrap();
+        }
         wallet.store.destroy(&mut tx);
     }
 
@@ -538,7 +646,8 @@ impl Wallets {
             return Err(WalletsError::AccountNotFound);
         }
         wallet.store.erase(&mut tx, pub_key);
-        Ok(())
+        self.delete_account_label(&mut tx, wallet_id, &pub_key.into())
+            .map_err(|_| WalletsError::Generic)
     }
 
     pub fn work_set(
@@ -580,16 +689,54 @@ impl Wallets {
     pub fn backup(&self, path: &Path) -> anyhow::Result<()> {
         let guard = self.mutex.lock().unwrap();
         let tx = self.env.tx_begin_read();
-        for (id, wallet) in guard.iter() {
-            std::fs::create_dir_all(path)?;
-            std::fs::set_permissions(path, Permissions::from_mode(0o700))?;
-            let mut backup_path = PathBuf::from(path);
-            backup_path.push(format!("{}.json", id));
-            wallet.store.write_backup(&tx, &backup_path)?;
+        let mut wallets: Vec<_> = guard.iter().collect();
+        wallets.sort_by_key(|(id, _)| **id);
+        let mut written = Vec::new();
+        for (id, wallet) in wallets {
+            Self::backup_wallet(&tx, path, id, wallet).with_context(|| {
+                format!(
+                    "Wallet backup failed, wallets already written: {:?}",
+                    written
+                )
+            })?;
+            written.push(*id);
         }
         Ok(())
     }
 
+    fn backup_wallet(
+        tx: &dyn Transaction,
+        path: &Path,
+        id: &WalletId,
+        wallet: &Wallet,
+    ) -> anyhow::Result<()> {
+        std::fs::create_dir_all(path)
+            .with_context(|| format!("Could not create backup directory {}", path.display()))?;
+        std::fs::set_permissions(path, Permissions::from_mode(0o700)).with_context(|| {
+            format!(
+                "Could not set permissions of backup directory {}",
+                path.display()
+            )
+        })?;
+        let backup_path = path.join(format!("{}.json", id));
+        // The backup is written to a temporary file first, so that a failure
+        // doesn't leave a partially written backup behind
+        let tmp_path = path.join(format!("{}.json.tmp", id));
+        let result = wallet
+            .store
+            .write_backup(tx, &tmp_path)
+            .with_context(|| format!("Could not write wallet backup {}", tmp_path.display()))
+            .and_then(|_| {
+                std::fs::rename(&tmp_path, &backup_path).with_context(|| {
+                    format!("Could not write wallet backup {}", backup_path.display())
+                })
+            });
+        if result.is_err() {
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+        result
+    }
+
     pub fn deterministic_index_get(&self, wallet_id: &WalletId) -> Result<u32, WalletsError> {
         let guard = self.mutex.lock().unwrap();
         let wallet = Self::get_wallet(&guard, wallet_id)?;
@@ -642,6 +789,67 @@ impl Wallets {
         Ok(PreparedSend::New(state_block, details))
     }
 
+    /// Creates a chain of send blocks starting at the current frontier of `source`
+    fn prepare_send_batch(
+        &self,
+        tx: &dyn Transaction,
+        wallet: &Arc<Wallet>,
+        source: Account,
+        sends: &[(Account, Amount)],
+    ) -> anyhow::Result<(Vec<Block>, BlockDetails)> {
+        let block_tx = self.ledger.read_txn();
+        if !wallet.store.valid_password(tx) {
+            bail!("invalid password");
+        }
+        let mut balance = self
+            .ledger
+            .any()
+            .account_balance(&block_tx, &source)
+            .unwrap_or_default();
+
+        let total = sends
+            .iter()
+            .try_fold(0u128, |total, (_, amount)| {
+                total.checked_add(amount.number())
+            })
+            .map(Amount::raw)
+            .ok_or_else(|| anyhow!("amount overflow"))?;
+        if balance.is_zero() || balance < total {
+            bail!("insufficient balance");
+        }
+
+        let info = self
+            .ledger
+            .account_info(&block_tx, &source)
+            .ok_or_else(|| anyhow!("account not found"))?;
+        let prv_key_raw = wallet.store.fetch(tx, &source.into())?;
+        let priv_key = PrivateKey::from(prv_key_raw);
+        let mut work = wallet
+            .store
+            .work_get(tx, &source.into())
+            .unwrap_or_default();
+        let mut previous = info.head;
+        let mut blocks = Vec::with_capacity(sends.len());
+        for (destination, amount) in sends {
+            balance = balance - *amount;
+            let block: Block = StateBlockArgs {
+                key: &priv_key,
+                previous,
+                representative: info.representative,
+                balance,
+                link: (*destination).into(),
+                work,
+            }
+            .into();
+            previous = block.hash();
+            // Cached work is only valid for the first block; the others get their work in action_complete
+            work = 0;
+            blocks.push(block);
+        }
+        let details = BlockDetails::new(info.epoch, true, false, false);
+        Ok((blocks, details))
+    }
+
     fn prepare_send_with_id(
         &self,
         tx: &mut LmdbWriteTransaction,
@@ -744,6 +952,43 @@ impl Wallets {
         accounts
     }
 
+    /// Number of accounts in all wallets.
+    /// Accounts that are contained in multiple wallets are only counted once
+    pub fn account_count(&self) -> usize {
+        let guard = self.mutex.lock().unwrap();
+        let tx = self.env.tx_begin_read();
+        guard
+            .values()
+            .flat_map(|wallet| wallet.store.iter(&tx).map(|(pub_key, _)| pub_key))
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    /// Sum of the ledger balances of all wallet accounts.
+    /// Accounts that are contained in multiple wallets are only counted once
+    pub fn total_balance(&self, include_only_confirmed: bool) -> Amount {
+        let guard = self.mutex.lock().unwrap();
+        let tx = self.env.tx_begin_read();
+        let block_tx = self.ledger.read_txn();
+        let mut accounts = HashSet::new();
+        let mut total = 0u128;
+        for wallet in guard.values() {
+            for (pub_key, _) in wallet.store.iter(&tx) {
+                let account = Account::from(pub_key);
+                if !accounts.insert(account) {
+                    continue;
+                }
+                let balance = if include_only_confirmed {
+                    self.ledger.confirmed().account_balance(&block_tx, &account)
+                } else {
+                    self.ledger.any().account_balance(&block_tx, &account)
+                };
+                total = total.saturating_add(balance.unwrap_or_default().number());
+            }
+        }
+        Amount::raw(total)
+    }
+
     pub fn get_accounts_of_wallet(
         &self,
         wallet_id: &WalletId,
@@ -828,6 +1073,24 @@ impl Wallets {
         Ok(wallet.store.seed(&tx))
     }
 
+    /// Returns the seed only if `password` matches the password of the unlocked wallet
+    pub fn export_seed(
+        &self,
+        wallet_id: &WalletId,
+        password: impl AsRef<str>,
+    ) -> Result<RawKey, WalletsError> {
+        let guard = self.mutex.lock().unwrap();
+        let wallet = Self::get_wallet(&guard, wallet_id)?;
+        let tx = self.env.tx_begin_read();
+        if !wallet.store.valid_password(&tx) {
+            return Err(WalletsError::WalletLocked);
+        }
+        if wallet.store.derive_key(&tx, password.as_ref()) != wallet.store.password() {
+            return Err(WalletsError::InvalidPassword);
+        }
+        Ok(wallet.store.seed(&tx))
+    }
+
     pub fn key_type(&self, wallet_id: WalletId, pub_key: &PublicKey) -> KeyType {
         let guard = self.mutex.lock().unwrap();
         match guard.get(&wallet_id) {
@@ -846,6 +1109,11 @@ impl Wallets {
         Ok(wallet.store.representative(&tx))
     }
 
+    /// The default representative which is used for opening new accounts of this wallet
+    pub fn wallet_representative(&self, wallet_id: &WalletId) -> Result<Account, WalletsError> {
+        self.get_representative(*wallet_id).map(Account::from)
+    }
+
     pub fn decrypt(&self, wallet_id: WalletId) -> Result<Vec<(PublicKey, RawKey)>, WalletsError> {
         let guard = self.mutex.lock().unwrap();
         let wallet = Self::get_wallet(&guard, &wallet_id)?;
@@ -921,6 +1189,15 @@ pub trait WalletsExt {
         generate_work: bool,
     ) -> Result<PublicKey, WalletsError>;
 
+    /// Creates `count` deterministic accounts. The returned flag tells whether
+    /// work is already cached for the account.
+    fn accounts_create_with_status(
+        &self,
+        wallet_id: &WalletId,
+        count: usize,
+        generate_work: bool,
+    ) -> Result<Vec<(Account, bool)>, WalletsError>;
+
     fn insert_adhoc(&self, wallet: &Arc<Wallet>, key: &RawKey, generate_work: bool) -> PublicKey;
 
     fn insert_adhoc2(
@@ -980,6 +1257,17 @@ pub trait WalletsExt {
         id: Option<String>,
     ) -> Result<SavedBlock, WalletsError>;
 
+    /// Sends to multiple destinations with a chain of blocks that is created from
+    /// the same starting frontier. If a block fails, all following sends are aborted
+    /// and reported as `None`.
+    fn send_action_batch(
+        &self,
+        wallet: &Arc<Wallet>,
+        source: Account,
+        sends: &[(Account, Amount)],
+        generate_work: bool,
+    ) -> Vec<Option<SavedBlock>>;
+
     fn change_action(
         &self,
         wallet: &Arc<Wallet>,
@@ -989,6 +1277,15 @@ pub trait WalletsExt {
         generate_work: bool,
     ) -> Option<Block>;
 
+    /// Changes the representative and waits until the change block is cemented
+    fn change_and_await_confirmation(
+        &self,
+        wallet: &Arc<Wallet>,
+        source: Account,
+        representative: PublicKey,
+        timeout: Duration,
+    ) -> anyhow::Result<Block>;
+
     fn change_action2(
         &self,
         wallet_id: &WalletId,
@@ -1096,6 +1393,15 @@ pub trait WalletsExt {
         wallet_tx: &dyn Transaction,
     ) -> Result<(), ()>;
 
+    /// Searches receivable blocks until all accounts are processed or `stop` is set.
+    /// Returns the number of processed accounts.
+    fn search_receivable_cancellable(
+        &self,
+        wallet: &Arc<Wallet>,
+        wallet_tx: &dyn Transaction,
+        stop: &AtomicBool,
+    ) -> Result<usize, ()>;
+
     fn receive_confirmed(&self, hash: BlockHash, destinaton: Account);
     fn search_receivable_all(&self);
     fn search_receivable_wallet(&self, wallet_id: WalletId) -> Result<(), WalletsError>;
@@ -1237,6 +1543,37 @@ impl WalletsExt for Arc<Wallets> {
         Ok(self.deterministic_insert(wallet, &mut tx, generate_work))
     }
 
+    fn accounts_create_with_status(
+        &self,
+        wallet_id: &WalletId,
+        count: usize,
+        generate_work: bool,
+    ) -> Result<Vec<(Account, bool)>, WalletsError> {
+        let guard = self.mutex.lock().unwrap();
+        let wallet = Wallets::get_wallet(&guard, wallet_id)?;
+        let mut tx = self.env.tx_begin_write();
+        if !wallet.store.valid_password(&tx) {
+            return Err(WalletsError::WalletLocked);
+        }
+
+        let keys: Vec<PublicKey> = (0..count)
+            .map(|_| self.deterministic_insert(wallet, &mut tx, generate_work))
+            .collect();
+
+        let result = keys
+            .into_iter()
+            .map(|key| {
+                let work_cached = wallet
+                    .store
+                    .work_get(&tx, &key)
+                    .map(|work| work != 0)
+                    .unwrap_or(false);
+                (key.into(), work_cached)
+            })
+            .collect();
+        Ok(result)
+    }
+
     fn insert_adhoc(&self, wallet: &Arc<Wallet>, key: &RawKey, generate_work: bool) -> PublicKey {
         let mut tx = self.env.tx_begin_write();
         if !wallet.store.valid_password(&tx) {
@@ -1445,6 +1782,34 @@ impl WalletsExt for Arc<Wallets> {
         }
     }
 
+    fn send_action_batch(
+        &self,
+        wallet: &Arc<Wallet>,
+        source: Account,
+        sends: &[(Account, Amount)],
+        generate_work: bool,
+    ) -> Vec<Option<SavedBlock>> {
+        let mut results = vec![None; sends.len()];
+        let prepared = {
+            let tx = self.env.tx_begin_read();
+            self.prepare_send_batch(&tx, wallet, source, sends)
+        };
+        let Ok((blocks, details)) = prepared else {
+            return results;
+        };
+
+        let last = blocks.len().saturating_sub(1);
+        for (i, block) in blocks.into_iter().enumerate() {
+            // Only pregenerate work for the block following the whole batch
+            let generate = generate_work && i == last;
+            match self.action_complete(Arc::clone(wallet), block, source, generate, &details) {
+                Ok(saved) => results[i] = Some(saved),
+                Err(_) => break,
+            }
+        }
+        results
+    }
+
     fn change_action(
         &self,
         wallet: &Arc<Wallet>,
@@ -1495,6 +1860,28 @@ impl WalletsExt for Arc<Wallets> {
             .map(|b| b.into())
     }
 
+    fn change_and_await_confirmation(
+        &self,
+        wallet: &Arc<Wallet>,
+        source: Account,
+        representative: PublicKey,
+        timeout: Duration,
+    ) -> anyhow::Result<Block> {
+        let block = self
+            .change_action(wallet, source, representative, 0, true)
+            .ok_or_else(|| anyhow!("could not create change block"))?;
+
+        if self.wait_cemented(&block.hash(), timeout) {
+            Ok(block)
+        } else {
+            bail!(
+                "change block {} was not confirmed within {} ms",
+                block.hash(),
+                timeout.as_millis()
+            )
+        }
+    }
+
     fn change_action2(
         &self,
         wallet_id: &WalletId,
@@ -1800,6 +2187,17 @@ impl WalletsExt for Arc<Wallets> {
         wallet: &Arc<Wallet>,
         wallet_tx: &dyn Transaction,
     ) -> Result<(), ()> {
+        let stop = self.wallet_actions.stop_flag();
+        self.search_receivable_cancellable(wallet, wallet_tx, &stop)
+            .map(|_| ())
+    }
+
+    fn search_receivable_cancellable(
+        &self,
+        wallet: &Arc<Wallet>,
+        wallet_tx: &dyn Transaction,
+        stop: &AtomicBool,
+    ) -> Result<usize, ()> {
         if !wallet.store.valid_password(wallet_tx) {
             info!("Stopping search, wallet is locked");
             return Err(());
@@ -1807,7 +2205,16 @@ impl WalletsExt for Arc<Wallets> {
 
         info!("Beginning receivable block search");
 
+        let mut processed = 0;
         for (account, wallet_value) in wallet.store.iter(wallet_tx) {
+            if stop.load(Ordering::SeqCst) {
+                info!(
+                    "Receivable block search stopped after {} accounts",
+                    processed
+                );
+                return Ok(processed);
+            }
+            processed += 1;
             let block_tx = self.ledger.read_txn();
             // Don't search pending for watch-only accounts
             if !wallet_value.key.is_zero() {
@@ -1856,8 +2263,11 @@ impl WalletsExt for Arc<Wallets> {
             }
         }
 
-        info!("Receivable block search phase completed");
-        Ok(())
+        info!(
+            "Receivable block search phase completed, {} accounts processed",
+            processed
+        );
+        Ok(processed)
     }
 
     fn receive_confirmed(&self, hash: BlockHash, destination: Account) {
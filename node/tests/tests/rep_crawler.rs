@@ -4,8 +4,11 @@ use rsnano_ledger::{
 };
 use rsnano_messages::{ConfirmAck, Message};
 use rsnano_network::{ChannelId, ChannelMode, DropPolicy, TrafficType};
-use rsnano_node::NodeExt;
-use std::{sync::Arc, time::Duration};
+use rsnano_node::{config::NodeConfig, NodeExt};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 use test_helpers::{assert_always_eq, assert_never, assert_timely_eq, System};
 
 #[test]
@@ -222,3 +225,69 @@ fn rep_connection_close() {
         0,
     );
 }
+
+#[test]
+fn probe_representative() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let result = Arc::new(Mutex::new(None));
+    let result2 = result.clone();
+    let hash = node
+        .rep_crawler
+        .probe_representative(
+            *DEV_GENESIS_ACCOUNT,
+            Box::new(move |voted| *result2.lock().unwrap() = Some(voted)),
+        )
+        .expect("no block to probe");
+
+    let vote = Arc::new(Vote::new(&DEV_GENESIS_KEY, 0, 0, vec![hash]));
+    node.rep_crawler.force_process(vote, ChannelId::from(42));
+
+    assert_timely_eq(
+        Duration::from_secs(5),
+        || *result.lock().unwrap(),
+        Some(true),
+    );
+}
+
+#[test]
+fn deduplicate_responses_of_same_representative() {
+    let mut system = System::new();
+    let node = system
+        .build_node()
+        .config(NodeConfig {
+            rep_crawler_weight_minimum: Amount::zero(),
+            ..System::default_config()
+        })
+        .finish();
+    let tracker = node
+        .online_reps
+        .lock()
+        .unwrap()
+        .track_vote_observed_directly();
+    let other_rep = PrivateKey::new();
+
+    node.rep_crawler.force_process_batch(vec![
+        (
+            Arc::new(Vote::new(&DEV_GENESIS_KEY, 0, 0, vec![*DEV_GENESIS_HASH])),
+            ChannelId::from(1),
+        ),
+        (
+            Arc::new(Vote::new(&other_rep, 0, 0, vec![*DEV_GENESIS_HASH])),
+            ChannelId::from(2),
+        ),
+        (
+            Arc::new(Vote::new(&DEV_GENESIS_KEY, 1, 0, vec![*DEV_GENESIS_HASH])),
+            ChannelId::from(1),
+        ),
+    ]);
+
+    assert_timely_eq(Duration::from_secs(5), || tracker.output().len(), 2);
+    assert_always_eq(Duration::from_millis(500), || tracker.output().len(), 2);
+    let mut observed = tracker.output();
+    observed.sort();
+    let mut expected = vec![*DEV_GENESIS_PUB_KEY, other_rep.public_key()];
+    expected.sort();
+    assert_eq!(observed, expected);
+}
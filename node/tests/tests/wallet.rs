@@ -11,7 +11,7 @@ use rsnano_node::{
     wallets::{WalletsError, WalletsExt},
     Node, DEV_NETWORK_PARAMS,
 };
-use rsnano_store_lmdb::{LmdbEnv, LmdbWalletStore};
+use rsnano_store_lmdb::{LmdbEnv, LmdbWalletStore, RekeyError};
 use std::{
     collections::HashSet,
     path::PathBuf,
@@ -398,6 +398,27 @@ fn rekey() {
     assert!(wallet.rekey(&mut tx, "2").is_err());
 }
 
+#[test]
+fn rekey_kdf_failure() {
+    let mut test_file = unique_path().unwrap();
+    test_file.push("wallet.ldb");
+    let env = LmdbEnv::new(test_file).unwrap();
+    let mut tx = env.tx_begin_write();
+    // argon2 rejects a memory cost of 0
+    let kdf = KeyDerivationFunction::new(0);
+    let wallet =
+        LmdbWalletStore::new(0, kdf, &mut tx, &DEV_GENESIS_PUB_KEY, &PathBuf::from("0")).unwrap();
+
+    let result = wallet.rekey(&mut tx, "1");
+
+    assert_eq!(result, Err(RekeyError::KdfFailure));
+    assert_eq!(
+        WalletsError::from(result.unwrap_err()),
+        WalletsError::KdfFailure
+    );
+    assert!(wallet.password().is_zero());
+}
+
 #[test]
 fn hash_password() {
     let mut test_file = unique_path().unwrap();
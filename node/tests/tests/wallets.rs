@@ -1,11 +1,18 @@
 use rsnano_core::{Amount, PrivateKey, UnsavedBlockLatticeBuilder, WalletId, DEV_GENESIS_KEY};
-use rsnano_ledger::{DEV_GENESIS_ACCOUNT, DEV_GENESIS_PUB_KEY};
+use rsnano_ledger::{DEV_GENESIS_ACCOUNT, DEV_GENESIS_HASH, DEV_GENESIS_PUB_KEY};
 use rsnano_node::{
     config::{NodeConfig, NodeFlags},
     consensus::ActiveElectionsExt,
-    wallets::WalletsExt,
+    unique_path,
+    wallets::{WalletsError, WalletsExt},
+};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
 };
-use std::time::Duration;
 use test_helpers::{assert_timely, assert_timely_eq, System};
 
 #[test]
@@ -189,3 +196,491 @@ fn search_receivable() {
         assert_eq!(send.hash(), receive.source().unwrap());
     }
 }
+
+#[test]
+fn wallet_representative() {
+    let mut system = System::new();
+    let node = system
+        .build_node()
+        .flags(NodeFlags {
+            disable_search_pending: true,
+            ..Default::default()
+        })
+        .finish();
+    let wallet_id = WalletId::random();
+    node.wallets.create(wallet_id);
+    let representative = PrivateKey::new();
+
+    node.wallets
+        .set_representative(wallet_id, representative.public_key(), false)
+        .unwrap();
+    assert_eq!(
+        node.wallets.wallet_representative(&wallet_id).unwrap(),
+        representative.account()
+    );
+
+    // A newly inserted account should be opened with the wallet's representative
+    let key = PrivateKey::new();
+    node.wallets
+        .insert_adhoc2(&wallet_id, &key.raw_key(), false)
+        .unwrap();
+    let mut lattice = UnsavedBlockLatticeBuilder::new();
+    let send = lattice.genesis().send(&key, node.config.receive_minimum);
+    node.process(send.clone()).unwrap();
+    node.confirm(send.hash());
+    node.wallets.search_receivable_wallet(wallet_id).unwrap();
+
+    assert_timely(Duration::from_secs(5), || {
+        node.ledger
+            .account_info(&node.ledger.read_txn(), &key.account())
+            .is_some()
+    });
+    let info = node
+        .ledger
+        .account_info(&node.ledger.read_txn(), &key.account())
+        .unwrap();
+    assert_eq!(info.representative, representative.public_key());
+}
+
+#[test]
+fn wallet_representative_wallet_not_found() {
+    let mut system = System::new();
+    let node = system.make_node();
+    assert_eq!(
+        node.wallets.wallet_representative(&WalletId::random()),
+        Err(WalletsError::WalletNotFound)
+    );
+}
+
+#[test]
+fn accounts_create_with_status_without_work() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = node.wallets.wallet_ids()[0];
+
+    let accounts = node
+        .wallets
+        .accounts_create_with_status(&wallet_id, 3, false)
+        .unwrap();
+
+    assert_eq!(accounts.len(), 3);
+    assert!(accounts.iter().all(|(_, work_cached)| !work_cached));
+    let delayed_work = node.wallets.delayed_work.lock().unwrap();
+    assert!(accounts
+        .iter()
+        .all(|(account, _)| !delayed_work.contains_key(account)));
+}
+
+#[test]
+fn accounts_create_with_status_generate_work() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = node.wallets.wallet_ids()[0];
+
+    let accounts = node
+        .wallets
+        .accounts_create_with_status(&wallet_id, 3, true)
+        .unwrap();
+
+    assert_eq!(accounts.len(), 3);
+    // Work generation is delayed, so no work is cached yet but it is scheduled
+    assert!(accounts.iter().all(|(_, work_cached)| !work_cached));
+    {
+        let delayed_work = node.wallets.delayed_work.lock().unwrap();
+        assert!(accounts
+            .iter()
+            .all(|(account, _)| delayed_work.contains_key(account)));
+    }
+
+    for (account, _) in &accounts {
+        assert_timely(Duration::from_secs(10), || {
+            node.wallets.work_get2(&wallet_id, &account.into()).unwrap() != 0
+        });
+    }
+}
+
+#[test]
+fn accounts_create_with_status_wallet_not_found() {
+    let mut system = System::new();
+    let node = system.make_node();
+    assert_eq!(
+        node.wallets
+            .accounts_create_with_status(&WalletId::random(), 1, false),
+        Err(WalletsError::WalletNotFound)
+    );
+}
+
+#[test]
+fn search_receivable_stops_when_wallet_actions_stop() {
+    let mut system = System::new();
+    let node = system
+        .build_node()
+        .flags(NodeFlags {
+            disable_search_pending: true,
+            ..Default::default()
+        })
+        .finish();
+    let wallet_id = WalletId::random();
+    node.wallets.create(wallet_id);
+
+    let mut lattice = UnsavedBlockLatticeBuilder::new();
+    for _ in 0..5 {
+        let key = PrivateKey::new();
+        node.wallets
+            .insert_adhoc2(&wallet_id, &key.raw_key(), false)
+            .unwrap();
+        let send = lattice.genesis().send(&key, node.config.receive_minimum);
+        node.process(send).unwrap();
+    }
+
+    // The unconfirmed receivable of the first account starts an election,
+    // which is when the wallet actions get stopped
+    let elections = Arc::new(AtomicUsize::new(0));
+    let elections_clone = elections.clone();
+    let wallets = Arc::downgrade(&node.wallets);
+    node.wallets.set_start_election_callback(Box::new(move |_| {
+        elections_clone.fetch_add(1, Ordering::SeqCst);
+        if let Some(wallets) = wallets.upgrade() {
+            wallets.stop();
+        }
+    }));
+
+    node.wallets.search_receivable_wallet(wallet_id).unwrap();
+
+    assert_eq!(elections.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn export_seed() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = WalletId::random();
+    node.wallets.create(wallet_id);
+    node.wallets.rekey(&wallet_id, "secret").unwrap();
+
+    let seed = node.wallets.export_seed(&wallet_id, "secret").unwrap();
+
+    assert_eq!(seed, node.wallets.get_seed(wallet_id).unwrap());
+}
+
+#[test]
+fn export_seed_wrong_password() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = WalletId::random();
+    node.wallets.create(wallet_id);
+    node.wallets.rekey(&wallet_id, "secret").unwrap();
+
+    assert_eq!(
+        node.wallets.export_seed(&wallet_id, "wrong"),
+        Err(WalletsError::InvalidPassword)
+    );
+    // The wallet must stay unlocked
+    assert_eq!(node.wallets.valid_password(&wallet_id), Ok(true));
+}
+
+#[test]
+fn export_seed_locked_wallet() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = WalletId::random();
+    node.wallets.create(wallet_id);
+    node.wallets.rekey(&wallet_id, "secret").unwrap();
+    node.wallets.lock(&wallet_id).unwrap();
+
+    assert_eq!(
+        node.wallets.export_seed(&wallet_id, "secret"),
+        Err(WalletsError::WalletLocked)
+    );
+}
+
+#[test]
+fn backup_reports_failing_directory() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let mut blocker = unique_path().unwrap();
+    blocker.push("blocker");
+    std::fs::write(&blocker, "not a directory").unwrap();
+    let backup_path = blocker.join("backup");
+
+    let error = node.wallets.backup(&backup_path).unwrap_err();
+
+    let message = format!("{:#}", error);
+    assert!(
+        message.contains(&backup_path.display().to_string()),
+        "{}",
+        message
+    );
+}
+
+#[test]
+fn backup_reports_written_wallets() {
+    let mut system = System::new();
+    let node = system.make_node();
+    node.wallets.create(WalletId::from(1));
+    node.wallets.create(WalletId::from(2));
+    let mut ids = node.wallets.wallet_ids();
+    ids.sort();
+    let failing = *ids.last().unwrap();
+    let written = &ids[..ids.len() - 1];
+
+    // A non-empty directory with the name of the backup file can't be replaced
+    let backup_path = unique_path().unwrap();
+    let blocker = backup_path.join(format!("{}.json", failing));
+    std::fs::create_dir_all(blocker.join("blocker")).unwrap();
+
+    let error = node.wallets.backup(&backup_path).unwrap_err();
+
+    let message = format!("{:#}", error);
+    assert!(
+        message.contains(&format!("wallets already written: {:?}", written)),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains(&blocker.display().to_string()),
+        "{}",
+        message
+    );
+    for id in written {
+        assert!(backup_path.join(format!("{}.json", id)).is_file());
+    }
+    assert_eq!(
+        backup_path.join(format!("{}.json.tmp", failing)).exists(),
+        false
+    );
+}
+
+#[test]
+fn send_action_batch() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = node.wallets.wallet_ids()[0];
+    node.wallets
+        .insert_adhoc2(&wallet_id, &DEV_GENESIS_KEY.raw_key(), false)
+        .unwrap();
+    let wallet = node
+        .wallets
+        .mutex
+        .lock()
+        .unwrap()
+        .get(&wallet_id)
+        .unwrap()
+        .clone();
+    let sends: Vec<_> = (1..=3)
+        .map(|i| (PrivateKey::from(i).account(), Amount::raw(i as u128)))
+        .collect();
+
+    let blocks = node
+        .wallets
+        .send_action_batch(&wallet, *DEV_GENESIS_ACCOUNT, &sends, false);
+
+    let blocks: Vec<_> = blocks.into_iter().map(|b| b.unwrap()).collect();
+    assert_eq!(blocks[0].previous(), *DEV_GENESIS_HASH);
+    assert_eq!(blocks[1].previous(), blocks[0].hash());
+    assert_eq!(blocks[2].previous(), blocks[1].hash());
+    for (block, (destination, _)) in blocks.iter().zip(&sends) {
+        assert_eq!(block.destination_or_link(), *destination);
+        assert!(node.block_exists(&block.hash()));
+    }
+}
+
+#[test]
+fn change_and_await_confirmation() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = node.wallets.wallet_ids()[0];
+    node.wallets
+        .insert_adhoc2(&wallet_id, &DEV_GENESIS_KEY.raw_key(), false)
+        .unwrap();
+    let wallet = node
+        .wallets
+        .mutex
+        .lock()
+        .unwrap()
+        .get(&wallet_id)
+        .unwrap()
+        .clone();
+    let representative = PrivateKey::new().public_key();
+
+    let change = node
+        .wallets
+        .change_and_await_confirmation(
+            &wallet,
+            *DEV_GENESIS_ACCOUNT,
+            representative,
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+    assert!(node.block_confirmed(&change.hash()));
+    assert_eq!(change.representative_field(), Some(representative));
+}
+
+#[test]
+fn account_labels() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = node.wallets.wallet_ids()[0];
+    let key = PrivateKey::new();
+    node.wallets
+        .insert_adhoc2(&wallet_id, &key.raw_key(), false)
+        .unwrap();
+    let account = key.account();
+
+    assert_eq!(node.wallets.get_account_label(&wallet_id, &account), None);
+
+    node.wallets
+        .set_account_label(&wallet_id, &account, "savings")
+        .unwrap();
+    assert_eq!(
+        node.wallets.get_account_label(&wallet_id, &account),
+        Some("savings".to_owned())
+    );
+
+    node.wallets
+        .set_account_label(&wallet_id, &account, "payroll")
+        .unwrap();
+    assert_eq!(
+        node.wallets.get_account_label(&wallet_id, &account),
+        Some("payroll".to_owned())
+    );
+
+    node.wallets
+        .clear_account_label(&wallet_id, &account)
+        .unwrap();
+    assert_eq!(node.wallets.get_account_label(&wallet_id, &account), None);
+}
+
+#[test]
+fn remove_key_deletes_account_label() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = node.wallets.wallet_ids()[0];
+    let key = PrivateKey::new();
+    node.wallets
+        .insert_adhoc2(&wallet_id, &key.raw_key(), false)
+        .unwrap();
+    node.wallets
+        .set_account_label(&wallet_id, &key.account(), "savings")
+        .unwrap();
+
+    node.wallets
+        .remove_key(&wallet_id, &key.public_key())
+        .unwrap();
+
+    node.wallets
+        .insert_adhoc2(&wallet_id, &key.raw_key(), false)
+        .unwrap();
+    assert_eq!(
+        node.wallets.get_account_label(&wallet_id, &key.account()),
+        None
+    );
+}
+
+#[test]
+fn destroy_deletes_account_labels() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = WalletId::random();
+    node.wallets.create(wallet_id);
+    let key = PrivateKey::new();
+    node.wallets
+        .insert_adhoc2(&wallet_id, &key.raw_key(), false)
+        .unwrap();
+    node.wallets
+        .set_account_label(&wallet_id, &key.account(), "savings")
+        .unwrap();
+
+    node.wallets.destroy(&wallet_id);
+
+    node.wallets.create(wallet_id);
+    node.wallets
+        .insert_adhoc2(&wallet_id, &key.raw_key(), false)
+        .unwrap();
+    assert_eq!(
+        node.wallets.get_account_label(&wallet_id, &key.account()),
+        None
+    );
+}
+
+#[test]
+fn account_label_for_unknown_account() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let wallet_id = node.wallets.wallet_ids()[0];
+    let account = PrivateKey::new().account();
+
+    assert_eq!(
+        node.wallets
+            .set_account_label(&wallet_id, &account, "label"),
+        Err(WalletsError::AccountNotFound)
+    );
+    assert_eq!(
+        node.wallets
+            .set_account_label(&WalletId::random(), &account, "label"),
+        Err(WalletsError::WalletNotFound)
+    );
+}
+
+#[test]
+fn account_count_and_total_balance() {
+    let mut system = System::new();
+    let node = system
+        .build_node()
+        .config(NodeConfig {
+            enable_voting: false,
+            ..System::default_config_without_backlog_population()
+        })
+        .finish();
+    let initial_count = node.wallets.account_count();
+    let wallet1 = WalletId::random();
+    let wallet2 = WalletId::random();
+    node.wallets.create(wallet1);
+    node.wallets.create(wallet2);
+    let key1 = PrivateKey::new();
+    let key2 = PrivateKey::new();
+    node.wallets
+        .insert_adhoc2(&wallet1, &DEV_GENESIS_KEY.raw_key(), false)
+        .unwrap();
+    node.wallets
+        .insert_adhoc2(&wallet2, &key1.raw_key(), false)
+        .unwrap();
+    node.wallets
+        .insert_adhoc2(&wallet2, &key2.raw_key(), false)
+        .unwrap();
+
+    let mut lattice = UnsavedBlockLatticeBuilder::new();
+    let send = lattice.genesis().send(&key1, Amount::raw(1000));
+    let open = lattice.account(&key1).receive(&send);
+    node.process(send.clone()).unwrap();
+    node.process(open).unwrap();
+    node.confirm(send.hash());
+
+    assert_eq!(node.wallets.account_count(), initial_count + 3);
+    assert_eq!(node.wallets.total_balance(false), Amount::MAX);
+    assert_eq!(
+        node.wallets.total_balance(true),
+        Amount::MAX - Amount::raw(1000)
+    );
+}
+
+#[test]
+fn account_count_counts_shared_accounts_once() {
+    let mut system = System::new();
+    let node = system.make_node();
+    let initial_count = node.wallets.account_count();
+    let wallet1 = WalletId::random();
+    let wallet2 = WalletId::random();
+    node.wallets.create(wallet1);
+    node.wallets.create(wallet2);
+    let key = PrivateKey::new();
+    node.wallets
+        .insert_adhoc2(&wallet1, &key.raw_key(), false)
+        .unwrap();
+    node.wallets
+        .insert_adhoc2(&wallet2, &key.raw_key(), false)
+        .unwrap();
+
+    assert_eq!(node.wallets.account_count(), initial_count + 1);
+}
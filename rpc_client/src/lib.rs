@@ -1,28 +1,140 @@
+mod transport;
+mod websocket;
+
+pub use transport::*;
+pub use websocket::*;
+
 use crate::AccountBalanceResponse;
 use anyhow::{anyhow, Ok, Result};
-use reqwest::Client;
+use rand::Rng;
 pub use reqwest::Url;
 use rsnano_core::{
-    Account, Amount, BlockHash, HashOrAccount, JsonBlock, PublicKey, RawKey, WalletId, WorkNonce,
+    Account, Amount, Block, BlockHash, HashOrAccount, JsonBlock, PublicKey, RawKey, WalletId,
+    WorkNonce,
 };
 use rsnano_rpc_messages::*;
-use serde::Serialize;
 use serde_json::Value;
 use std::time::Duration;
 
+/// Observes every command that is sent and the response that was received
+pub type RpcLogger = Box<dyn Fn(&RpcCommand, &Value) + Send + Sync>;
+
 pub struct NanoRpcClient {
+    transport: Box<dyn RpcTransport>,
+    logger: Option<RpcLogger>,
+    max_retries: usize,
+    retry_base_delay: Duration,
+    websocket_url: Option<Url>,
+    websocket_max_backoff: Option<Duration>,
+}
+
+pub struct NanoRpcClientBuilder {
     url: Url,
-    client: Client,
+    websocket_url: Option<Url>,
+    websocket_max_backoff: Option<Duration>,
+    timeout: Duration,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+}
+
+impl NanoRpcClientBuilder {
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Maximum number of idle connections that are kept open to the node
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Idle connections are closed after this timeout
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Url of the node's websocket server, needed for the subscriptions
+    pub fn websocket_url(mut self, url: Url) -> Self {
+        self.websocket_url = Some(url);
+        self
+    }
+
+    /// Reconnect websocket subscriptions when the connection drops.
+    /// The delay between attempts doubles up to `max_backoff`.
+    pub fn websocket_reconnect(mut self, max_backoff: Duration) -> Self {
+        self.websocket_max_backoff = Some(max_backoff);
+        self
+    }
+
+    pub fn build(self) -> NanoRpcClient {
+        let websocket_url = self.websocket_url.clone();
+        let websocket_max_backoff = self.websocket_max_backoff;
+        NanoRpcClient {
+            transport: Box::new(self.http_transport()),
+            logger: None,
+            max_retries: 0,
+            retry_base_delay: Duration::ZERO,
+            websocket_url,
+            websocket_max_backoff,
+        }
+    }
+
+    fn http_transport(self) -> HttpTransport {
+        let mut builder = reqwest::ClientBuilder::new().timeout(self.timeout);
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+
+        HttpTransport::new(self.url, builder.build().unwrap())
+    }
 }
 
 impl NanoRpcClient {
     pub fn new(url: Url) -> Self {
+        Self::builder(url).build()
+    }
+
+    /// Sends all commands through the given transport instead of HTTP
+    pub fn with_transport(transport: impl RpcTransport + 'static) -> Self {
         Self {
+            transport: Box::new(transport),
+            logger: None,
+            max_retries: 0,
+            retry_base_delay: Duration::ZERO,
+            websocket_url: None,
+            websocket_max_backoff: None,
+        }
+    }
+
+    /// Calls `logger` with each outgoing command and its response.
+    /// Transport failures are reported as `{"error": "..."}`.
+    pub fn set_logger(&mut self, logger: RpcLogger) {
+        self.logger = Some(logger);
+    }
+
+    /// Retry requests that failed because of a connection or timeout error.
+    /// The delay between attempts starts at `base_delay` and doubles with
+    /// each retry, plus some random jitter. Errors returned by the node are
+    /// never retried.
+    pub fn with_retries(mut self, max_retries: usize, base_delay: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_base_delay = base_delay;
+        self
+    }
+
+    pub fn builder(url: Url) -> NanoRpcClientBuilder {
+        NanoRpcClientBuilder {
             url,
-            client: reqwest::ClientBuilder::new()
-                .timeout(Duration::from_secs(5))
-                .build()
-                .unwrap(),
+            websocket_url: None,
+            websocket_max_backoff: None,
+            timeout: Duration::from_secs(5),
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
         }
     }
 
@@ -119,24 +231,10 @@ impl NanoRpcClient {
 
     pub async fn receivable(&self, args: impl Into<ReceivableArgs>) -> Result<ReceivableResponse> {
         let args = args.into();
-        let source: bool = args.source.unwrap_or_default().into();
-        let min_version: bool = args.min_version.unwrap_or_default().into();
-        let sort: bool = args.sorting.unwrap_or_default().into();
-        let simple =
-            args.threshold.unwrap_or_default().is_zero() && !source && !min_version && !sort;
-
+        let shape = ReceivableShape::new(&args);
         let cmd = RpcCommand::Receivable(args);
         let result = self.request_raw(&cmd).await?;
-        if simple {
-            let blocks = serde_json::from_value::<ReceivableSimple>(result)?;
-            Ok(ReceivableResponse::Simple(blocks))
-        } else if source || min_version {
-            let blocks = serde_json::from_value::<ReceivableSource>(result)?;
-            Ok(ReceivableResponse::Source(blocks))
-        } else {
-            let blocks = serde_json::from_value::<ReceivableThreshold>(result)?;
-            Ok(ReceivableResponse::Threshold(blocks))
-        }
+        shape.parse(result)
     }
 
     pub async fn accounts_receivable(
@@ -351,7 +449,7 @@ impl NanoRpcClient {
         self.request(&RpcCommand::account_weight(account)).await
     }
 
-    pub async fn available_supply(&self) -> Result<AvailableSupplyReponse> {
+    pub async fn available_supply(&self) -> Result<AvailableSupplyResponse> {
         self.request(&RpcCommand::AvailableSupply).await
     }
 
@@ -375,7 +473,12 @@ impl NanoRpcClient {
         self.request(&RpcCommand::FrontierCount).await
     }
 
-    pub async fn validate_account_number(
+    /// Validates prefix, length and checksum without contacting the node
+    pub fn validate_account_number_local(&self, account: &str) -> bool {
+        Account::decode_account(account).is_ok()
+    }
+
+    pub async fn validate_account_number_remote(
         &self,
         account: impl Into<String>,
     ) -> Result<ValidResponse> {
@@ -383,11 +486,25 @@ impl NanoRpcClient {
         self.request(&cmd).await
     }
 
-    pub async fn nano_to_raw(&self, amount: u64) -> Result<AmountRpcMessage> {
+    /// Converts whole Nano to raw without contacting the node
+    pub fn nano_to_raw(&self, amount: u64) -> Result<Amount> {
+        (amount as u128)
+            .checked_mul(Amount::nano(1).number())
+            .map(Amount::raw)
+            .ok_or_else(|| anyhow!("Invalid amount number"))
+    }
+
+    /// Converts raw to whole Nano without contacting the node.
+    /// Fractions of a Nano are truncated, like the RPC does.
+    pub fn raw_to_nano(&self, amount: Amount) -> u64 {
+        (amount.number() / Amount::nano(1).number()) as u64
+    }
+
+    pub async fn nano_to_raw_remote(&self, amount: u64) -> Result<AmountRpcMessage> {
         self.request(&RpcCommand::nano_to_raw(amount)).await
     }
 
-    pub async fn raw_to_nano(&self, amount: Amount) -> Result<AmountRpcMessage> {
+    pub async fn raw_to_nano_remote(&self, amount: Amount) -> Result<AmountRpcMessage> {
         self.request(&RpcCommand::raw_to_nano(amount)).await
     }
 
@@ -443,8 +560,16 @@ impl NanoRpcClient {
         self.request(&RpcCommand::wallet_info(wallet)).await
     }
 
-    pub async fn wallet_export(&self, wallet: WalletId) -> Result<JsonResponse> {
-        self.request(&RpcCommand::wallet_export(wallet)).await
+    /// Returns the wallet serialized as JSON, in the format that `Wallets::import` accepts
+    pub async fn wallet_export(&self, wallet: WalletId) -> Result<String> {
+        let response: JsonResponse = self.request(&RpcCommand::wallet_export(wallet)).await?;
+        Ok(response.json)
+    }
+
+    /// Same as `wallet_export`, but parses the exported JSON
+    pub async fn wallet_export_json(&self, wallet: WalletId) -> Result<Value> {
+        let json = self.wallet_export(wallet).await?;
+        Ok(serde_json::from_str(&json)?)
     }
 
     pub async fn password_change(
@@ -466,9 +591,7 @@ impl NanoRpcClient {
     }
 
     pub async fn password_valid(&self, wallet: WalletId) -> Result<ValidResponse> {
-        let cmd = RpcCommand::password_valid(wallet);
-        let result = self.request_raw(&cmd).await?;
-        Ok(serde_json::from_value(result)?)
+        self.request(&RpcCommand::password_valid(wallet)).await
     }
 
     pub async fn deterministic_key(&self, seed: RawKey, index: u32) -> Result<KeyPairDto> {
@@ -491,6 +614,7 @@ impl NanoRpcClient {
         }
     }
 
+    /// Requires `enable_control` in the node's RPC config
     pub async fn populate_backlog(&self) -> Result<SuccessResponse> {
         self.request(&RpcCommand::PopulateBacklog).await
     }
@@ -522,7 +646,7 @@ impl NanoRpcClient {
         self.request(&RpcCommand::UncheckedClear).await
     }
 
-    pub async fn unopened(&self, args: impl Into<UnopenedArgs>) -> Result<AccountsWithAmountsDto> {
+    pub async fn unopened(&self, args: impl Into<UnopenedArgs>) -> Result<UnopenedResponse> {
         self.request(&RpcCommand::Unopened(args.into())).await
     }
 
@@ -530,6 +654,7 @@ impl NanoRpcClient {
         self.request(&RpcCommand::node_id()).await
     }
 
+    /// Requires `enable_control` in the node's RPC config
     pub async fn search_receivable_all(&self) -> Result<SuccessResponse> {
         self.request(&RpcCommand::search_receivable_all()).await
     }
@@ -554,7 +679,12 @@ impl NanoRpcClient {
         self.request(&RpcCommand::delegators_count(account)).await
     }
 
-    pub async fn block_hash(&self, block: JsonBlock) -> Result<HashRpcMessage> {
+    /// Computes the hash of the block without contacting the node
+    pub fn block_hash_local(&self, block: JsonBlock) -> BlockHash {
+        Block::from(block).hash()
+    }
+
+    pub async fn block_hash_remote(&self, block: JsonBlock) -> Result<HashRpcMessage> {
         self.request(&RpcCommand::block_hash(block)).await
     }
 
@@ -582,16 +712,16 @@ impl NanoRpcClient {
         self.request(&RpcCommand::blocks(blocks)).await
     }
 
-    pub async fn blocks_info(&self, blocks: Vec<BlockHash>) -> Result<BlocksInfoResponse> {
-        self.request(&RpcCommand::blocks_info(blocks)).await
+    pub async fn blocks_info(&self, args: impl Into<BlocksInfoArgs>) -> Result<BlocksInfoResponse> {
+        self.request(&RpcCommand::blocks_info(args)).await
     }
 
     pub async fn successors(&self, args: impl Into<ChainArgs>) -> Result<BlockHashesResponse> {
         self.request(&RpcCommand::Successors(args.into())).await
     }
 
-    pub async fn chain(&self, args: ChainArgs) -> Result<BlockHashesResponse> {
-        self.request(&RpcCommand::Chain(args)).await
+    pub async fn chain(&self, args: impl Into<ChainArgs>) -> Result<BlockHashesResponse> {
+        self.request(&RpcCommand::Chain(args.into())).await
     }
 
     pub async fn confirmation_active(
@@ -673,7 +803,20 @@ impl NanoRpcClient {
         Ok(())
     }
 
-    pub async fn stats(&self, stats_type: StatsType) -> Result<serde_json::Value> {
+    /// Fetches the `counters` or `samples` stats. The other stats types have no
+    /// entries and must be fetched with `stats_raw`.
+    pub async fn stats(&self, stats_type: StatsType) -> Result<StatsResponse> {
+        if !matches!(stats_type, StatsType::Counters | StatsType::Samples) {
+            return Err(anyhow!(
+                "{:?} stats have no entries, use stats_raw",
+                stats_type
+            ));
+        }
+        self.request(&RpcCommand::Stats(StatsArgs { stats_type }))
+            .await
+    }
+
+    pub async fn stats_raw(&self, stats_type: StatsType) -> Result<serde_json::Value> {
         self.request_raw(&RpcCommand::Stats(StatsArgs { stats_type }))
             .await
     }
@@ -682,9 +825,8 @@ impl NanoRpcClient {
         self.request(&RpcCommand::Version).await
     }
 
-    async fn request<T, R>(&self, cmd: &T) -> Result<R>
+    async fn request<R>(&self, cmd: &RpcCommand) -> Result<R>
     where
-        T: Serialize,
         R: serde::de::DeserializeOwned,
     {
         let value = self.request_raw(cmd).await?;
@@ -692,21 +834,594 @@ impl NanoRpcClient {
         Ok(result)
     }
 
-    async fn request_raw<T>(&self, request: &T) -> Result<serde_json::Value>
-    where
-        T: Serialize,
-    {
-        let result = self
-            .client
-            .post(self.url.clone())
-            .json(request)
-            .send()
-            .await?
-            .error_for_status()?
-            .json::<Value>()
-            .await?;
-
-        check_error(&result).map_err(|e| anyhow!("node returned error: \"{}\"", e))?;
+    async fn request_raw(&self, cmd: &RpcCommand) -> Result<serde_json::Value> {
+        let result = self.send_with_retries(cmd).await;
+        if let Some(logger) = &self.logger {
+            match &result {
+                Ok(value) => logger(cmd, value),
+                Err(e) => logger(cmd, &serde_json::json!({ "error": e.to_string() })),
+            }
+        }
+        let result = result?;
+
+        check_error(&result).map_err(NodeRpcError::from)?;
         Ok(result)
     }
+
+    async fn send_with_retries(&self, cmd: &RpcCommand) -> Result<Value> {
+        let mut attempt = 0;
+        loop {
+            match self.transport.send(cmd).await {
+                Err(e) if attempt < self.max_retries && is_transient(&e) => {
+                    tokio::time::sleep(retry_delay(self.retry_base_delay, attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Only connection failures, timeouts and 5xx responses are worth retrying
+fn is_transient(error: &anyhow::Error) -> bool {
+    if let Some(e) = error.downcast_ref::<reqwest::Error>() {
+        e.is_connect() || e.is_timeout() || e.status().is_some_and(|s| s.is_server_error())
+    } else {
+        error.downcast_ref::<TransportError>().is_some()
+    }
+}
+
+fn retry_delay(base_delay: Duration, attempt: usize) -> Duration {
+    let delay = base_delay.saturating_mul(1 << attempt.min(16));
+    let jitter = delay.mul_f64(rand::thread_rng().gen_range(0.0..0.5));
+    delay + jitter
+}
+
+/// An error that was returned by the node
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NodeRpcError {
+    /// The command requires `enable_control` in the node's RPC config
+    ControlDisabled,
+    /// The wallet password was wrong. The user can be asked to try again
+    InvalidPassword,
+    BlockNotFound,
+    Other(String),
+}
+
+impl From<String> for NodeRpcError {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "RPC control is disabled" => Self::ControlDisabled,
+            "Invalid password" => Self::InvalidPassword,
+            "Block not found" => Self::BlockNotFound,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl std::fmt::Display for NodeRpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::ControlDisabled => "RPC control is disabled",
+            Self::InvalidPassword => "Invalid password",
+            Self::BlockNotFound => "Block not found",
+            Self::Other(message) => message,
+        };
+        write!(f, "node returned error: \"{}\"", message)
+    }
+}
+
+impl std::error::Error for NodeRpcError {}
+
+/// The layout of a `receivable` response depends on the options that were requested
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum ReceivableShape {
+    Simple,
+    Source,
+    Threshold,
+}
+
+impl ReceivableShape {
+    fn new(args: &ReceivableArgs) -> Self {
+        let source: bool = args.source.unwrap_or_default().into();
+        let min_version: bool = args.min_version.unwrap_or_default().into();
+        let sort: bool = args.sorting.unwrap_or_default().into();
+        let threshold = args.threshold.unwrap_or_default();
+        if threshold.is_zero() && !source && !min_version && !sort {
+            Self::Simple
+        } else if source || min_version {
+            Self::Source
+        } else {
+            Self::Threshold
+        }
+    }
+
+    fn parse(self, result: Value) -> Result<ReceivableResponse> {
+        Ok(match self {
+            Self::Simple => ReceivableResponse::Simple(serde_json::from_value(result)?),
+            Self::Source => ReceivableResponse::Source(serde_json::from_value(result)?),
+            Self::Threshold => ReceivableResponse::Threshold(serde_json::from_value(result)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    #[test]
+    fn build_client_with_tuned_pool() {
+        let url = Url::parse("http://[::1]:7076/").unwrap();
+        let transport = NanoRpcClient::builder(url.clone())
+            .pool_max_idle_per_host(32)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .http_transport();
+
+        assert_eq!(transport.url, url);
+        assert!(transport.client.post(transport.url.clone()).build().is_ok());
+    }
+
+    #[tokio::test]
+    async fn account_balance_in_memory() {
+        let account = Account::from(42);
+        let rpc = InMemoryRpc::new();
+        rpc.respond(
+            RpcCommand::AccountBalance(AccountBalanceArgs::new(account)),
+            serde_json::json!({
+                "balance": "1000",
+                "pending": "10",
+                "receivable": "10"
+            }),
+        );
+        let client = NanoRpcClient::with_transport(rpc);
+
+        let balance = client.account_balance(account).await.unwrap();
+
+        assert_eq!(balance.balance, Amount::raw(1000));
+        assert_eq!(balance.pending, Amount::raw(10));
+        assert_eq!(balance.receivable, Amount::raw(10));
+    }
+
+    #[tokio::test]
+    async fn log_requests_and_responses() {
+        let response = serde_json::json!({ "success": "" });
+        let rpc = InMemoryRpc::new();
+        rpc.respond(RpcCommand::Stop, response.clone());
+        let mut client = NanoRpcClient::with_transport(rpc);
+        let logged = Arc::new(Mutex::new(Vec::new()));
+        let logged2 = logged.clone();
+        client.set_logger(Box::new(move |cmd, response| {
+            logged2
+                .lock()
+                .unwrap()
+                .push((serde_json::to_value(cmd).unwrap(), response.clone()));
+        }));
+
+        client.stop().await.unwrap();
+
+        assert_eq!(
+            *logged.lock().unwrap(),
+            vec![(serde_json::json!({ "action": "stop" }), response)]
+        );
+    }
+
+    struct FlakyTransport {
+        failures: usize,
+        attempts: Arc<AtomicUsize>,
+        response: Value,
+    }
+
+    #[async_trait::async_trait]
+    impl RpcTransport for FlakyTransport {
+        async fn send(&self, _cmd: &RpcCommand) -> Result<Value> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst);
+            if attempt < self.failures {
+                Err(TransportError::Connect("connection refused".to_owned()).into())
+            } else {
+                Ok(self.response.clone())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_transport_errors() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let client = NanoRpcClient::with_transport(FlakyTransport {
+            failures: 2,
+            attempts: attempts.clone(),
+            response: serde_json::json!({ "success": "" }),
+        })
+        .with_retries(3, Duration::from_millis(1));
+
+        client.stop().await.unwrap();
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn give_up_after_max_retries() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let client = NanoRpcClient::with_transport(FlakyTransport {
+            failures: 5,
+            attempts: attempts.clone(),
+            response: serde_json::json!({ "success": "" }),
+        })
+        .with_retries(2, Duration::from_millis(1));
+
+        assert!(client.stop().await.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    struct BrokenTransport {
+        attempts: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl RpcTransport for BrokenTransport {
+        async fn send(&self, _cmd: &RpcCommand) -> Result<Value> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            Err(anyhow!("invalid response"))
+        }
+    }
+
+    #[tokio::test]
+    async fn dont_retry_permanent_transport_errors() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let client = NanoRpcClient::with_transport(BrokenTransport {
+            attempts: attempts.clone(),
+        })
+        .with_retries(3, Duration::from_millis(1));
+
+        assert!(client.stop().await.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn transient_errors() {
+        assert!(is_transient(
+            &TransportError::Connect("connection refused".to_owned()).into()
+        ));
+        assert!(is_transient(&TransportError::Timeout.into()));
+        assert!(is_transient(&TransportError::ServerError(503).into()));
+        assert_eq!(is_transient(&anyhow!("invalid response")), false);
+    }
+
+    #[tokio::test]
+    async fn dont_retry_node_errors() {
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let client = NanoRpcClient::with_transport(FlakyTransport {
+            failures: 0,
+            attempts: attempts.clone(),
+            response: serde_json::json!({ "error": "RPC control is disabled" }),
+        })
+        .with_retries(3, Duration::from_millis(1));
+
+        assert!(client.stop().await.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn retry_delay_doubles() {
+        let base = Duration::from_millis(100);
+        for attempt in 0..4 {
+            let delay = retry_delay(base, attempt);
+            let expected = base * (1 << attempt);
+            assert!(delay >= expected && delay < expected * 3 / 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn in_memory_node_error() {
+        let rpc = InMemoryRpc::new();
+        rpc.respond(
+            RpcCommand::Stop,
+            serde_json::json!({ "error": "RPC control is disabled" }),
+        );
+        let client = NanoRpcClient::with_transport(rpc);
+
+        let error = client.stop().await.unwrap_err();
+
+        assert_eq!(
+            error.downcast_ref::<NodeRpcError>(),
+            Some(&NodeRpcError::ControlDisabled)
+        );
+    }
+
+    fn test_client() -> NanoRpcClient {
+        NanoRpcClient::new(Url::parse("http://[::1]:7076/").unwrap())
+    }
+
+    #[test]
+    fn map_control_disabled_error() {
+        let error = NodeRpcError::from("RPC control is disabled".to_string());
+        assert_eq!(error, NodeRpcError::ControlDisabled);
+        assert_eq!(
+            error.to_string(),
+            "node returned error: \"RPC control is disabled\""
+        );
+    }
+
+    #[test]
+    fn map_invalid_password_error() {
+        let error = NodeRpcError::from("Invalid password".to_string());
+        assert_eq!(error, NodeRpcError::InvalidPassword);
+        assert_eq!(
+            error.to_string(),
+            "node returned error: \"Invalid password\""
+        );
+    }
+
+    #[test]
+    fn map_block_not_found_error() {
+        let error = NodeRpcError::from("Block not found".to_string());
+        assert_eq!(error, NodeRpcError::BlockNotFound);
+        assert_eq!(
+            error.to_string(),
+            "node returned error: \"Block not found\""
+        );
+    }
+
+    #[test]
+    fn map_other_node_error() {
+        let error = NodeRpcError::from("Bad account number".to_string());
+        assert_eq!(error, NodeRpcError::Other("Bad account number".to_string()));
+        assert_eq!(
+            error.to_string(),
+            "node returned error: \"Bad account number\""
+        );
+    }
+
+    #[test]
+    fn receivable_simple_shape() {
+        let args = ReceivableArgs::new(Account::from(1));
+        let shape = ReceivableShape::new(&args);
+        assert_eq!(shape, ReceivableShape::Simple);
+
+        let response = shape
+            .parse(serde_json::json!({ "blocks": [BlockHash::from(2)] }))
+            .unwrap();
+        let ReceivableResponse::Simple(simple) = response else {
+            panic!("expected simple response");
+        };
+        assert_eq!(simple.blocks, vec![BlockHash::from(2)]);
+    }
+
+    #[test]
+    fn receivable_threshold_shape() {
+        let args = ReceivableArgs::build(Account::from(1))
+            .threshold(Amount::raw(1))
+            .finish();
+        let shape = ReceivableShape::new(&args);
+        assert_eq!(shape, ReceivableShape::Threshold);
+
+        let hash = BlockHash::from(2);
+        let response = shape
+            .parse(serde_json::json!({ "blocks": { hash.to_string(): "100" } }))
+            .unwrap();
+        let ReceivableResponse::Threshold(threshold) = response else {
+            panic!("expected threshold response");
+        };
+        assert_eq!(threshold.blocks[&hash], Amount::raw(100));
+    }
+
+    #[test]
+    fn receivable_source_shape() {
+        let args = ReceivableArgs::build(Account::from(1)).source().finish();
+        let shape = ReceivableShape::new(&args);
+        assert_eq!(shape, ReceivableShape::Source);
+
+        let hash = BlockHash::from(2);
+        let response = shape
+            .parse(serde_json::json!({
+                "blocks": {
+                    hash.to_string(): {
+                        "amount": "100",
+                        "source": Account::from(3)
+                    }
+                }
+            }))
+            .unwrap();
+        let ReceivableResponse::Source(source) = response else {
+            panic!("expected source response");
+        };
+        let info = &source.blocks[&hash];
+        assert_eq!(info.amount, Amount::raw(100));
+        assert_eq!(info.source, Some(Account::from(3)));
+    }
+
+    #[test]
+    fn validate_account_number_local() {
+        let client = test_client();
+        let nano_address = Account::from(42).encode_account();
+        let xrb_address = nano_address.replacen("nano_", "xrb_", 1);
+        assert!(client.validate_account_number_local(&nano_address));
+        assert!(client.validate_account_number_local(&xrb_address));
+    }
+
+    #[test]
+    fn validate_account_number_local_rejects_invalid() {
+        let client = test_client();
+        let address = Account::from(42).encode_account();
+
+        let mut bad_checksum = address.clone();
+        let last = bad_checksum.pop().unwrap();
+        bad_checksum.push(if last == '1' { '3' } else { '1' });
+        assert!(!client.validate_account_number_local(&bad_checksum));
+
+        let bad_prefix = address.replacen("nano_", "nan0_", 1);
+        assert!(!client.validate_account_number_local(&bad_prefix));
+
+        assert!(!client.validate_account_number_local(&address[..20]));
+        assert!(!client.validate_account_number_local(""));
+    }
+
+    #[test]
+    fn block_hash_local() {
+        let block = Block::new_test_instance();
+        let hash = test_client().block_hash_local(block.json_representation());
+        assert_eq!(
+            hash,
+            BlockHash::decode_hex(
+                "D9E4A975D8C4E7FE6F3569B6B60EE19D7C090C5B6E316416DC36F8C90264DF60"
+            )
+            .unwrap()
+        );
+        assert_eq!(hash, block.hash());
+    }
+
+    #[test]
+    fn nano_to_raw_local() {
+        let client = test_client();
+        assert_eq!(client.nano_to_raw(0).unwrap(), Amount::zero());
+        assert_eq!(
+            client.nano_to_raw(1).unwrap(),
+            Amount::raw(1_000_000_000_000_000_000_000_000_000_000)
+        );
+        assert_eq!(
+            client.nano_to_raw(133_248_297).unwrap(),
+            Amount::raw(133_248_297_000_000_000_000_000_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn nano_to_raw_local_overflow() {
+        assert!(test_client().nano_to_raw(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn raw_to_nano_local() {
+        let client = test_client();
+        assert_eq!(
+            client.raw_to_nano(Amount::raw(1_000_000_000_000_000_000_000_000_000_000)),
+            1
+        );
+        assert_eq!(
+            client.raw_to_nano(Amount::raw(12_400_000_000_000_000_000_000_000_000_000)),
+            12
+        );
+        assert_eq!(client.raw_to_nano(Amount::raw(999)), 0);
+        assert_eq!(client.raw_to_nano(Amount::MAX), 340_282_366);
+    }
+
+    fn in_memory_client(cmd: RpcCommand, response: Value) -> NanoRpcClient {
+        let rpc = InMemoryRpc::new();
+        rpc.respond(cmd, response);
+        NanoRpcClient::with_transport(rpc)
+    }
+
+    #[tokio::test]
+    async fn wallet_info() {
+        let wallet = WalletId::from(1);
+        let client = in_memory_client(
+            RpcCommand::wallet_info(wallet),
+            serde_json::json!({
+                "balance": "10000",
+                "pending": "2000",
+                "receivable": "2000",
+                "accounts_count": "3",
+                "adhoc_count": "1",
+                "deterministic_count": "2",
+                "deterministic_index": "4",
+                "accounts_block_count": "7",
+                "accounts_cemented_block_count": "5"
+            }),
+        );
+
+        let info = client.wallet_info(wallet).await.unwrap();
+
+        assert_eq!(info.balance, Amount::raw(10000));
+        assert_eq!(info.pending, Amount::raw(2000));
+        assert_eq!(info.receivable, Amount::raw(2000));
+        assert_eq!(info.accounts_count, 3.into());
+        assert_eq!(info.adhoc_count, 1.into());
+        assert_eq!(info.deterministic_count, 2.into());
+        assert_eq!(info.deterministic_index, 4.into());
+        assert_eq!(info.accounts_block_count, 7.into());
+        assert_eq!(info.accounts_cemented_block_count, 5.into());
+    }
+
+    #[tokio::test]
+    async fn password_valid() {
+        let wallet = WalletId::from(1);
+        let client = in_memory_client(
+            RpcCommand::password_valid(wallet),
+            serde_json::json!({ "valid": "1" }),
+        );
+
+        let result = client.password_valid(wallet).await.unwrap();
+
+        assert_eq!(result.valid, true.into());
+    }
+
+    #[tokio::test]
+    async fn password_enter_invalid() {
+        let wallet = WalletId::from(1);
+        let client = in_memory_client(
+            RpcCommand::password_enter(wallet, "wrong".to_string()),
+            serde_json::json!({ "valid": "0" }),
+        );
+
+        let result = client
+            .password_enter(wallet, "wrong".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(result.valid, false.into());
+    }
+
+    #[tokio::test]
+    async fn password_change() {
+        let wallet = WalletId::from(1);
+        let client = in_memory_client(
+            RpcCommand::password_change(wallet, "new".to_string()),
+            serde_json::json!({ "changed": "1" }),
+        );
+
+        assert!(client
+            .password_change(wallet, "new".to_string())
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn password_change_with_invalid_password() {
+        let wallet = WalletId::from(1);
+        let client = in_memory_client(
+            RpcCommand::password_change(wallet, "new".to_string()),
+            serde_json::json!({ "error": "Invalid password" }),
+        );
+
+        let error = client
+            .password_change(wallet, "new".to_string())
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            error.downcast_ref::<NodeRpcError>(),
+            Some(&NodeRpcError::InvalidPassword)
+        );
+    }
+
+    #[tokio::test]
+    async fn wallet_export() {
+        let wallet = WalletId::from(1);
+        let exported =
+            r#"{"0000000000000000000000000000000000000000000000000000000000000001":"ABCD"}"#;
+        let client = in_memory_client(
+            RpcCommand::wallet_export(wallet),
+            serde_json::json!({ "json": exported }),
+        );
+
+        assert_eq!(client.wallet_export(wallet).await.unwrap(), exported);
+        assert_eq!(
+            client.wallet_export_json(wallet).await.unwrap(),
+            serde_json::json!({
+                "0000000000000000000000000000000000000000000000000000000000000001": "ABCD"
+            })
+        );
+    }
 }
@@ -0,0 +1,108 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use reqwest::{Client, Url};
+use rsnano_rpc_messages::RpcCommand;
+use serde_json::Value;
+use std::sync::Mutex;
+
+/// Sends an RPC command to a node and returns the raw JSON response
+#[async_trait]
+pub trait RpcTransport: Send + Sync {
+    async fn send(&self, cmd: &RpcCommand) -> Result<Value>;
+}
+
+/// Failure of a transport that isn't based on reqwest, which is worth retrying.
+/// Any other error of a custom transport is considered permanent.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TransportError {
+    /// The node could not be reached
+    Connect(String),
+    /// The node didn't respond in time
+    Timeout,
+    /// The node responded with a 5xx status code
+    ServerError(u16),
+}
+
+impl std::fmt::Display for TransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransportError::Connect(reason) => write!(f, "could not connect to node: {}", reason),
+            TransportError::Timeout => write!(f, "node did not respond in time"),
+            TransportError::ServerError(status) => {
+                write!(f, "node responded with status {}", status)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TransportError {}
+
+/// Sends the commands as HTTP POST requests to the node's RPC server
+pub struct HttpTransport {
+    pub(crate) url: Url,
+    pub(crate) client: Client,
+}
+
+impl HttpTransport {
+    pub fn new(url: Url, client: Client) -> Self {
+        Self { url, client }
+    }
+}
+
+#[async_trait]
+impl RpcTransport for HttpTransport {
+    async fn send(&self, cmd: &RpcCommand) -> Result<Value> {
+        let result = self
+            .client
+            .post(self.url.clone())
+            .json(cmd)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<Value>()
+            .await?;
+        Ok(result)
+    }
+}
+
+/// Returns canned responses without talking to a node. Useful for testing
+/// code that consumes `NanoRpcClient`.
+#[derive(Default)]
+pub struct InMemoryRpc {
+    responses: Mutex<Vec<(Value, Value)>>,
+    sent: Mutex<Vec<Value>>,
+}
+
+impl InMemoryRpc {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Respond with `response` whenever `cmd` is sent
+    pub fn respond(&self, cmd: RpcCommand, response: Value) {
+        let key = serde_json::to_value(cmd).unwrap();
+        let mut responses = self.responses.lock().unwrap();
+        responses.retain(|(k, _)| *k != key);
+        responses.push((key, response));
+    }
+
+    /// All commands that were sent so far, serialized as JSON
+    pub fn sent(&self) -> Vec<Value> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl RpcTransport for InMemoryRpc {
+    async fn send(&self, cmd: &RpcCommand) -> Result<Value> {
+        let key = serde_json::to_value(cmd)?;
+        self.sent.lock().unwrap().push(key.clone());
+        self.responses
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, response)| response.clone())
+            .ok_or_else(|| anyhow!("no response configured for {}", key))
+    }
+}
@@ -0,0 +1,546 @@
+use crate::NanoRpcClient;
+use anyhow::{anyhow, bail, Result};
+use futures_util::{future, SinkExt, Stream, StreamExt};
+use reqwest::Url;
+use rsnano_core::{Account, BlockHash, NodeId, Signature};
+use rsnano_rpc_messages::{RpcU16, RpcU32, RpcU64, RpcU8};
+use rsnano_websocket_messages::{OutgoingMessageEnvelope, Topic};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{
+    collections::HashSet,
+    net::{Ipv6Addr, SocketAddrV6},
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+/// A vote that was received by the node
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+pub struct VoteEvent {
+    pub account: Account,
+    pub signature: Signature,
+    pub sequence: RpcU64,
+    pub timestamp: RpcU64,
+    pub blocks: Vec<BlockHash>,
+    /// "vote", "replay" or "indeterminate"
+    #[serde(rename = "type")]
+    pub vote_type: String,
+}
+
+/// Telemetry data that the node received from one of its peers
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+pub struct TelemetryEvent {
+    pub node_id: NodeId,
+    pub address: Ipv6Addr,
+    pub port: RpcU16,
+    pub block_count: RpcU64,
+    pub cemented_count: RpcU64,
+    pub unchecked_count: RpcU64,
+    pub account_count: RpcU64,
+    pub peer_count: RpcU32,
+    pub protocol_version: RpcU8,
+    pub major_version: RpcU8,
+    pub minor_version: RpcU8,
+    pub patch_version: RpcU8,
+    pub genesis_block: BlockHash,
+}
+
+impl TelemetryEvent {
+    pub fn endpoint(&self) -> SocketAddrV6 {
+        SocketAddrV6::new(self.address, self.port.inner(), 0, 0)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConnectionStatus {
+    Connecting,
+    Connected,
+    /// The connection was lost and will be reestablished after a backoff
+    Reconnecting,
+    Closed,
+}
+
+/// The events of a websocket topic subscription
+pub struct Subscription<T> {
+    messages: Pin<Box<dyn Stream<Item = Value> + Send>>,
+    parse: Box<dyn FnMut(Value) -> Option<T> + Send>,
+    status: Arc<Mutex<ConnectionStatus>>,
+}
+
+impl<T> Subscription<T> {
+    pub fn status(&self) -> ConnectionStatus {
+        *self.status.lock().unwrap()
+    }
+}
+
+impl<T> Stream for Subscription<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        loop {
+            match this.messages.as_mut().poll_next(cx) {
+                Poll::Ready(Some(message)) => {
+                    if let Some(event) = (this.parse)(message) {
+                        return Poll::Ready(Some(event));
+                    }
+                }
+                Poll::Ready(None) => {
+                    *this.status.lock().unwrap() = ConnectionStatus::Closed;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Keeps a websocket topic subscription alive. When the connection drops, it
+/// reconnects with exponential backoff and resends the subscribe frame.
+pub struct ReconnectingWebSocket {
+    messages: mpsc::Receiver<Value>,
+    status: Arc<Mutex<ConnectionStatus>>,
+}
+
+impl ReconnectingWebSocket {
+    const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+
+    /// Must be called from within a tokio runtime
+    pub fn connect(url: Url, topic: Topic, options: Option<Value>, max_backoff: Duration) -> Self {
+        let (tx, rx) = mpsc::channel(1024);
+        let status = Arc::new(Mutex::new(ConnectionStatus::Connecting));
+        tokio::spawn(keep_subscribed(
+            url,
+            topic,
+            options,
+            max_backoff,
+            tx,
+            status.clone(),
+        ));
+        Self {
+            messages: rx,
+            status,
+        }
+    }
+
+    pub fn status(&self) -> ConnectionStatus {
+        *self.status.lock().unwrap()
+    }
+}
+
+impl Stream for ReconnectingWebSocket {
+    type Item = Value;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Value>> {
+        self.messages.poll_recv(cx)
+    }
+}
+
+async fn keep_subscribed(
+    url: Url,
+    topic: Topic,
+    options: Option<Value>,
+    max_backoff: Duration,
+    tx: mpsc::Sender<Value>,
+    status: Arc<Mutex<ConnectionStatus>>,
+) {
+    let set_status = |new_status| *status.lock().unwrap() = new_status;
+    let mut backoff = ReconnectingWebSocket::INITIAL_BACKOFF.min(max_backoff);
+    while !tx.is_closed() {
+        if let Ok(mut messages) = subscribe(&url, topic, options.clone()).await {
+            set_status(ConnectionStatus::Connected);
+            backoff = ReconnectingWebSocket::INITIAL_BACKOFF.min(max_backoff);
+            loop {
+                tokio::select! {
+                    message = messages.next() => match message {
+                        Some(message) => {
+                            if tx.send(message).await.is_err() {
+                                break;
+                            }
+                        }
+                        None => break,
+                    },
+                    _ = tx.closed() => break,
+                }
+            }
+        }
+
+        if tx.is_closed() {
+            break;
+        }
+        set_status(ConnectionStatus::Reconnecting);
+        tokio::time::sleep(backoff).await;
+        backoff = next_backoff(backoff, max_backoff);
+    }
+    set_status(ConnectionStatus::Closed);
+}
+
+impl NanoRpcClient {
+    /// Subscribes to the `telemetry` topic of the node's websocket
+    pub async fn telemetry_subscribe(&self) -> Result<Subscription<TelemetryEvent>> {
+        self.subscribe_topic(Topic::Telemetry, None, |message| {
+            serde_json::from_value::<TelemetryEvent>(message).ok()
+        })
+        .await
+    }
+
+    /// Subscribes to the `vote` topic of the node's websocket.
+    /// If `representatives` is set, only votes of these representatives are yielded.
+    pub async fn vote_subscribe(
+        &self,
+        representatives: Option<Vec<Account>>,
+        include_replays: bool,
+        include_indeterminate: bool,
+    ) -> Result<Subscription<VoteEvent>> {
+        let mut options = json!({
+            "include_replays": include_replays,
+            "include_indeterminate": include_indeterminate,
+        });
+        if let Some(reps) = &representatives {
+            options["representatives"] = reps.iter().map(|r| r.encode_account()).collect();
+        }
+
+        let filter: Option<HashSet<Account>> = representatives.map(|r| r.into_iter().collect());
+        self.subscribe_topic(Topic::Vote, Some(options), move |message| {
+            let vote = serde_json::from_value::<VoteEvent>(message).ok();
+            vote.filter(|v| match &filter {
+                Some(reps) => reps.contains(&v.account),
+                None => true,
+            })
+        })
+        .await
+    }
+
+    async fn subscribe_topic<T>(
+        &self,
+        topic: Topic,
+        options: Option<Value>,
+        parse: impl FnMut(Value) -> Option<T> + Send + 'static,
+    ) -> Result<Subscription<T>> {
+        let url = self
+            .websocket_url
+            .as_ref()
+            .ok_or_else(|| anyhow!("no websocket url configured"))?;
+
+        let messages: Pin<Box<dyn Stream<Item = Value> + Send>>;
+        let status;
+        match self.websocket_max_backoff {
+            Some(max_backoff) => {
+                let ws = ReconnectingWebSocket::connect(url.clone(), topic, options, max_backoff);
+                status = ws.status.clone();
+                messages = Box::pin(ws);
+            }
+            None => {
+                messages = Box::pin(subscribe(url, topic, options).await?);
+                status = Arc::new(Mutex::new(ConnectionStatus::Connected));
+            }
+        }
+
+        Ok(Subscription {
+            messages,
+            parse: Box::new(parse),
+            status,
+        })
+    }
+}
+
+fn next_backoff(backoff: Duration, max_backoff: Duration) -> Duration {
+    (backoff * 2).min(max_backoff)
+}
+
+fn subscribe_frame(topic: Topic, options: Option<Value>) -> String {
+    let mut frame = json!({
+        "action": "subscribe",
+        "topic": topic,
+        "ack": true,
+    });
+    if let Some(options) = options {
+        frame["options"] = options;
+    }
+    frame.to_string()
+}
+
+/// Connects to the websocket, subscribes to the given topic and waits for the
+/// acknowledgement. The returned stream yields the message bodies of that topic
+/// and ends when the connection is closed.
+async fn subscribe(
+    url: &Url,
+    topic: Topic,
+    options: Option<Value>,
+) -> Result<impl Stream<Item = Value> + Send + Unpin> {
+    let (mut ws, _) = connect_async(url.as_str()).await?;
+    ws.send(Message::Text(subscribe_frame(topic, options)))
+        .await?;
+
+    match ws.next().await {
+        Some(Ok(Message::Text(text))) => {
+            let envelope: OutgoingMessageEnvelope = serde_json::from_str(&text)?;
+            if envelope.ack.as_deref() != Some("subscribe") {
+                bail!("expected subscribe ack, got: {}", text);
+            }
+        }
+        Some(Ok(other)) => bail!("expected subscribe ack, got: {:?}", other),
+        Some(Err(e)) => return Err(e.into()),
+        None => bail!("websocket closed before subscription was acknowledged"),
+    }
+
+    Ok(ws
+        .take_while(|msg| future::ready(matches!(msg, Ok(m) if !m.is_close())))
+        .filter_map(move |msg| {
+            future::ready(match msg {
+                Ok(Message::Text(text)) => serde_json::from_str::<OutgoingMessageEnvelope>(&text)
+                    .ok()
+                    .filter(|envelope| envelope.topic == Some(topic))
+                    .and_then(|envelope| envelope.message),
+                _ => None,
+            })
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnano_core::PrivateKey;
+    use std::net::Ipv4Addr;
+    use tokio::{net::TcpListener, task::JoinHandle};
+    use tokio_tungstenite::accept_async;
+
+    #[tokio::test]
+    async fn vote_subscribe() {
+        let rep = PrivateKey::from(1);
+        let other_rep = PrivateKey::from(2);
+        let frames = vec![
+            vote_frame(&other_rep.account(), BlockHash::from(100)),
+            vote_frame(&rep.account(), BlockHash::from(200)),
+        ];
+        let (url, mut subscribe_rx, server) = start_stub_server(vec![frames]).await;
+        let client = NanoRpcClient::builder(Url::parse("http://[::1]:7076/").unwrap())
+            .websocket_url(url)
+            .build();
+
+        let mut votes = client
+            .vote_subscribe(Some(vec![rep.account()]), true, false)
+            .await
+            .unwrap();
+
+        let subscribe: Value = serde_json::from_str(&subscribe_rx.recv().await.unwrap()).unwrap();
+        assert_eq!(
+            subscribe,
+            json!({
+                "action": "subscribe",
+                "topic": "vote",
+                "ack": true,
+                "options": {
+                    "include_replays": true,
+                    "include_indeterminate": false,
+                    "representatives": [rep.account().encode_account()]
+                }
+            })
+        );
+
+        let vote = votes.next().await.unwrap();
+        assert_eq!(vote.account, rep.account());
+        assert_eq!(vote.blocks, vec![BlockHash::from(200)]);
+        assert_eq!(vote.sequence, 1234.into());
+        assert_eq!(vote.vote_type, "vote");
+
+        // Stream ends when the node closes the connection
+        assert!(votes.next().await.is_none());
+        assert_eq!(votes.status(), ConnectionStatus::Closed);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn telemetry_subscribe() {
+        let node_id = NodeId::from(PrivateKey::from(3).public_key());
+        let telemetry = json!({
+            "topic": "telemetry",
+            "time": "1000",
+            "message": {
+                "block_count": "100",
+                "cemented_count": "90",
+                "unchecked_count": "5",
+                "account_count": "20",
+                "bandwidth_cap": "10485760",
+                "peer_count": "7",
+                "protocol_version": "21",
+                "uptime": "1000",
+                "genesis_block": BlockHash::from(1),
+                "major_version": "27",
+                "minor_version": "0",
+                "patch_version": "0",
+                "pre_release_version": "0",
+                "maker": "1",
+                "timestamp": "1000",
+                "active_difficulty": "fffffff800000000",
+                "node_id": node_id.to_string(),
+                "signature": Signature::new().encode_hex(),
+                "address": "::ffff:127.0.0.1",
+                "port": "7075"
+            }
+        });
+        let (url, mut subscribe_rx, server) =
+            start_stub_server(vec![vec![telemetry.to_string()]]).await;
+        let client = NanoRpcClient::builder(Url::parse("http://[::1]:7076/").unwrap())
+            .websocket_url(url)
+            .build();
+
+        let mut events = client.telemetry_subscribe().await.unwrap();
+
+        let subscribe: Value = serde_json::from_str(&subscribe_rx.recv().await.unwrap()).unwrap();
+        assert_eq!(
+            subscribe,
+            json!({ "action": "subscribe", "topic": "telemetry", "ack": true })
+        );
+
+        // the ack frame is not yielded as an event
+        let event = events.next().await.unwrap();
+        assert_eq!(event.node_id, node_id);
+        assert_eq!(
+            event.endpoint(),
+            SocketAddrV6::new(Ipv4Addr::LOCALHOST.to_ipv6_mapped(), 7075, 0, 0)
+        );
+        assert_eq!(event.block_count, 100.into());
+        assert_eq!(event.cemented_count, 90.into());
+        assert_eq!(event.protocol_version, 21.into());
+        assert!(events.next().await.is_none());
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn subscribe_fails_without_ack() {
+        let listener = TcpListener::bind("[::1]:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(stream).await.unwrap();
+            ws.next().await;
+            ws.close(None).await.unwrap();
+        });
+        let client = NanoRpcClient::builder(Url::parse("http://[::1]:7076/").unwrap())
+            .websocket_url(Url::parse(&format!("ws://[::1]:{}", port)).unwrap())
+            .build();
+
+        assert!(client.telemetry_subscribe().await.is_err());
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reconnect_after_connection_drop() {
+        let rep = PrivateKey::from(1);
+        let (url, mut subscribe_rx, server) = start_stub_server(vec![
+            vec![vote_frame(&rep.account(), BlockHash::from(100))],
+            vec![vote_frame(&rep.account(), BlockHash::from(200))],
+        ])
+        .await;
+        let client = NanoRpcClient::builder(Url::parse("http://[::1]:7076/").unwrap())
+            .websocket_url(url)
+            .websocket_reconnect(Duration::from_millis(10))
+            .build();
+
+        let mut votes = client
+            .vote_subscribe(Some(vec![rep.account()]), false, false)
+            .await
+            .unwrap();
+
+        assert_eq!(votes.next().await.unwrap().blocks, [BlockHash::from(100)]);
+        assert_eq!(votes.next().await.unwrap().blocks, [BlockHash::from(200)]);
+
+        let first_subscribe = subscribe_rx.recv().await.unwrap();
+        let second_subscribe = subscribe_rx.recv().await.unwrap();
+        assert_eq!(first_subscribe, second_subscribe);
+
+        server.await.unwrap();
+        let start = std::time::Instant::now();
+        while votes.status() != ConnectionStatus::Reconnecting {
+            assert!(start.elapsed() < Duration::from_secs(5), "not reconnecting");
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn reconnecting_while_node_unreachable() {
+        let ws = ReconnectingWebSocket::connect(
+            Url::parse("ws://[::1]:1").unwrap(),
+            Topic::Vote,
+            None,
+            Duration::from_millis(5),
+        );
+        let start = std::time::Instant::now();
+        while ws.status() != ConnectionStatus::Reconnecting {
+            assert!(start.elapsed() < Duration::from_secs(5), "not reconnecting");
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_max() {
+        let max = Duration::from_secs(1);
+        assert_eq!(
+            next_backoff(Duration::from_millis(100), max),
+            Duration::from_millis(200)
+        );
+        assert_eq!(next_backoff(Duration::from_millis(600), max), max);
+        assert_eq!(next_backoff(max, max), max);
+    }
+
+    #[tokio::test]
+    async fn vote_subscribe_without_websocket_url() {
+        let client = NanoRpcClient::new(Url::parse("http://[::1]:7076/").unwrap());
+        assert!(client.vote_subscribe(None, false, false).await.is_err());
+    }
+
+    fn vote_frame(account: &Account, block: BlockHash) -> String {
+        json!({
+            "topic": "vote",
+            "time": "1000",
+            "message": {
+                "account": account.encode_account(),
+                "signature": Signature::new().encode_hex(),
+                "sequence": "1234",
+                "timestamp": "1234",
+                "duration": "0",
+                "blocks": [block],
+                "type": "vote"
+            }
+        })
+        .to_string()
+    }
+
+    /// Accepts one websocket connection per entry in `connections`. Each connection
+    /// acknowledges the subscription, sends its frames and is closed again.
+    /// The received subscribe frames are forwarded to the returned receiver.
+    async fn start_stub_server(
+        connections: Vec<Vec<String>>,
+    ) -> (Url, mpsc::UnboundedReceiver<String>, JoinHandle<()>) {
+        let listener = TcpListener::bind("[::1]:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (subscribe_tx, subscribe_rx) = mpsc::unbounded_channel();
+
+        let server = tokio::spawn(async move {
+            for frames in connections {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = accept_async(stream).await.unwrap();
+
+                let Some(Ok(Message::Text(subscribe))) = ws.next().await else {
+                    panic!("expected subscribe frame");
+                };
+                subscribe_tx.send(subscribe).unwrap();
+
+                let ack = json!({"ack": "subscribe", "time": "1000"});
+                ws.send(Message::Text(ack.to_string())).await.unwrap();
+                for frame in frames {
+                    ws.send(Message::Text(frame)).await.unwrap();
+                }
+                ws.close(None).await.unwrap();
+            }
+        });
+
+        let url = Url::parse(&format!("ws://[::1]:{}", port)).unwrap();
+        (url, subscribe_rx, server)
+    }
+}
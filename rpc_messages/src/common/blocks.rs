@@ -1,8 +1,10 @@
+use super::deserialize_empty_as_default;
 use rsnano_core::BlockHash;
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct BlockHashesResponse {
+    #[serde(deserialize_with = "deserialize_empty_as_default")]
     pub blocks: Vec<BlockHash>,
 }
 
@@ -43,4 +45,36 @@ mod tests {
 
         assert_eq!(deserialized, expected);
     }
+
+    #[test]
+    fn deserialize_blocks_dto_keeps_order() {
+        let json_str = r#"{
+            "blocks": [
+                "000D1BAEC8EC208142C99059B393051BAC8380F9B5A2E6B2489A277D81789F3F",
+                "0000000000000000000000000000000000000000000000000000000000000000"
+            ]
+        }"#;
+
+        let deserialized: BlockHashesResponse = from_str(json_str).unwrap();
+
+        let expected = BlockHashesResponse::new(vec![
+            BlockHash::decode_hex(
+                "000D1BAEC8EC208142C99059B393051BAC8380F9B5A2E6B2489A277D81789F3F",
+            )
+            .unwrap(),
+            BlockHash::zero(),
+        ]);
+        assert_eq!(deserialized, expected);
+    }
+
+    #[test]
+    fn deserialize_empty_blocks_string() {
+        let deserialized: BlockHashesResponse = from_str(r#"{"blocks": ""}"#).unwrap();
+        assert_eq!(deserialized, BlockHashesResponse::new(Vec::new()));
+    }
+
+    #[test]
+    fn deserialize_non_empty_blocks_string_fails() {
+        assert!(from_str::<BlockHashesResponse>(r#"{"blocks": "foo"}"#).is_err());
+    }
 }
@@ -0,0 +1,25 @@
+use serde::{de::Error, Deserialize, Deserializer};
+
+/// The node returns an empty string instead of an empty array or object when
+/// a collection is empty. This deserializes both into the collection.
+pub(crate) fn deserialize_empty_as_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum CollectionHelper<T> {
+        Collection(T),
+        Empty(String),
+    }
+
+    match CollectionHelper::<T>::deserialize(deserializer)? {
+        CollectionHelper::Collection(collection) => Ok(collection),
+        CollectionHelper::Empty(s) if s.is_empty() => Ok(T::default()),
+        CollectionHelper::Empty(s) => Err(D::Error::custom(format!(
+            "expected a collection or an empty string, got \"{}\"",
+            s
+        ))),
+    }
+}
@@ -13,3 +13,21 @@ impl ExistsResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_exists() {
+        let exists: ExistsResponse = serde_json::from_value(json!({"exists": "1"})).unwrap();
+        assert_eq!(exists, ExistsResponse::new(true));
+    }
+
+    #[test]
+    fn deserialize_not_exists() {
+        let exists: ExistsResponse = serde_json::from_value(json!({"exists": "0"})).unwrap();
+        assert_eq!(exists, ExistsResponse::new(false));
+    }
+}
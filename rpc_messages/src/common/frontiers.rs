@@ -143,4 +143,30 @@ mod tests {
         let expected_frontiers_dto = FrontiersResponse::new(frontiers);
         assert_eq!(deserialized, expected_frontiers_dto);
     }
+
+    #[test]
+    fn deserialize_empty_frontiers() {
+        let deserialized: FrontiersResponse = serde_json::from_str(r#"{"frontiers": {}}"#).unwrap();
+        assert_eq!(deserialized, FrontiersResponse::new(HashMap::new()));
+    }
+
+    #[test]
+    fn deserialize_multiple_frontiers() {
+        let json = serde_json::json!({
+            "frontiers": {
+                Account::from(1).encode_account(): BlockHash::from(10).encode_hex(),
+                Account::from(2).encode_account(): BlockHash::from(20).encode_hex(),
+            }
+        });
+
+        let deserialized: FrontiersResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            deserialized,
+            FrontiersResponse::new(HashMap::from([
+                (Account::from(1), BlockHash::from(10)),
+                (Account::from(2), BlockHash::from(20)),
+            ]))
+        );
+    }
 }
@@ -7,6 +7,7 @@ mod block;
 mod blocks;
 mod count;
 mod destroyed;
+mod empty_collection;
 mod error;
 mod exists;
 mod frontiers;
@@ -48,6 +49,8 @@ pub use success::*;
 pub use valid::*;
 pub use wallet::*;
 
+pub(crate) use empty_collection::deserialize_empty_as_default;
+
 use rsnano_core::{BlockSubType, BlockType, WorkVersion};
 use serde::{Deserialize, Serialize};
 
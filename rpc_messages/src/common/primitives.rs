@@ -76,6 +76,7 @@ rpc_number!(RpcU16, u16, RpcU16Visitor);
 rpc_number!(RpcU32, u32, RpcU32Visitor);
 rpc_number!(RpcU64, u64, RpcU64Visitor);
 rpc_number!(RpcUsize, usize, RpcUsizeVisitor);
+rpc_number!(RpcI64, i64, RpcI64Visitor);
 
 impl From<RpcU64> for usize {
     fn from(value: RpcU64) -> Self {
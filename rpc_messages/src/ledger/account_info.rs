@@ -369,6 +369,70 @@ mod tests {
         assert!(serialized.contains("confirmed_representative"));
     }
 
+    #[test]
+    fn deserialize_minimal_account_info_response() {
+        let json = r#"{
+            "frontier": "0000000000000000000000000000000000000000000000000000000000000001",
+            "open_block": "0000000000000000000000000000000000000000000000000000000000000002",
+            "representative_block": "0000000000000000000000000000000000000000000000000000000000000003",
+            "balance": "1000",
+            "modified_timestamp": "1234567890",
+            "block_count": "3",
+            "account_version": "1"
+        }"#;
+
+        let response: AccountInfoResponse = from_str(json).unwrap();
+
+        assert_eq!(response.frontier, BlockHash::from(1));
+        assert_eq!(response.open_block, BlockHash::from(2));
+        assert_eq!(response.representative_block, BlockHash::from(3));
+        assert_eq!(response.balance, Amount::raw(1000));
+        assert_eq!(response.block_count, 3.into());
+        assert_eq!(response.representative, None);
+        assert_eq!(response.weight, None);
+        assert_eq!(response.receivable, None);
+        assert_eq!(response.confirmed_height, None);
+        assert_eq!(response.confirmed_frontier, None);
+    }
+
+    #[test]
+    fn deserialize_expanded_account_info_response() {
+        let json = r#"{
+            "frontier": "0000000000000000000000000000000000000000000000000000000000000001",
+            "open_block": "0000000000000000000000000000000000000000000000000000000000000002",
+            "representative_block": "0000000000000000000000000000000000000000000000000000000000000003",
+            "balance": "1000",
+            "confirmed_balance": "900",
+            "modified_timestamp": "1234567890",
+            "block_count": "3",
+            "account_version": "1",
+            "confirmed_height": "2",
+            "confirmation_height": "2",
+            "confirmed_frontier": "0000000000000000000000000000000000000000000000000000000000000004",
+            "representative": "nano_111111111111111111111111111111111111111111111111115uwdgas549",
+            "confirmed_representative": "nano_111111111111111111111111111111111111111111111111115uwdgas549",
+            "weight": "2000",
+            "pending": "300",
+            "receivable": "300",
+            "confirmed_pending": "200",
+            "confirmed_receivable": "200"
+        }"#;
+
+        let response: AccountInfoResponse = from_str(json).unwrap();
+
+        assert_eq!(response.confirmed_balance, Some(Amount::raw(900)));
+        assert_eq!(response.confirmed_height, Some(2.into()));
+        assert_eq!(response.confirmation_height, Some(2.into()));
+        assert_eq!(response.confirmed_frontier, Some(BlockHash::from(4)));
+        assert_eq!(response.representative, Some(Account::from(123)));
+        assert_eq!(response.confirmed_representative, Some(Account::from(123)));
+        assert_eq!(response.weight, Some(Amount::raw(2000)));
+        assert_eq!(response.pending, Some(Amount::raw(300)));
+        assert_eq!(response.receivable, Some(Amount::raw(300)));
+        assert_eq!(response.confirmed_pending, Some(Amount::raw(200)));
+        assert_eq!(response.confirmed_receivable, Some(Amount::raw(200)));
+    }
+
     #[test]
     fn deserialize_account_info_dto_with_some_values() {
         let account_info = create_account_info_dto_with_some_values();
@@ -9,11 +9,11 @@ impl RpcCommand {
 }
 
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
-pub struct AvailableSupplyReponse {
+pub struct AvailableSupplyResponse {
     pub available: Amount,
 }
 
-impl AvailableSupplyReponse {
+impl AvailableSupplyResponse {
     pub fn new(available: Amount) -> Self {
         Self { available }
     }
@@ -21,7 +21,8 @@ impl AvailableSupplyReponse {
 
 #[cfg(test)]
 mod tests {
-    use crate::RpcCommand;
+    use crate::{ledger::AvailableSupplyResponse, RpcCommand};
+    use rsnano_core::Amount;
     use serde_json::{from_str, to_string_pretty};
 
     #[test]
@@ -41,4 +42,14 @@ mod tests {
         let deserialized: RpcCommand = from_str(&serialized).unwrap();
         assert_eq!(cmd, deserialized)
     }
+
+    #[test]
+    fn deserialize_available_supply_response() {
+        let json = r#"{"available": "133248061996216572282917317807824970865"}"#;
+        let response: AvailableSupplyResponse = from_str(json).unwrap();
+        assert_eq!(
+            response.available,
+            Amount::raw(133248061996216572282917317807824970865)
+        );
+    }
 }
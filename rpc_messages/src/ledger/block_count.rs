@@ -73,4 +73,20 @@ mod tests {
         let deserialized: BlockCountResponse = from_str(&serialized).unwrap();
         assert_eq!(bool_dto, deserialized);
     }
+
+    #[test]
+    fn deserialize_node_block_count_response() {
+        let json = r#"{"count": "1000", "unchecked": "10", "cemented": "25"}"#;
+        let response: BlockCountResponse = from_str(json).unwrap();
+        assert_eq!(
+            response,
+            BlockCountResponse {
+                count: 1000.into(),
+                unchecked: 10.into(),
+                cemented: 25.into(),
+                full: None,
+                pruned: None,
+            }
+        );
+    }
 }
@@ -39,6 +39,44 @@ pub struct BlocksInfoArgs {
     pub hashes: Vec<BlockHash>,
 }
 
+impl BlocksInfoArgs {
+    pub fn builder(hashes: Vec<BlockHash>) -> BlocksInfoArgsBuilder {
+        BlocksInfoArgsBuilder {
+            args: hashes.into(),
+        }
+    }
+}
+
+pub struct BlocksInfoArgsBuilder {
+    args: BlocksInfoArgs,
+}
+
+impl BlocksInfoArgsBuilder {
+    pub fn include_receivable(mut self) -> Self {
+        self.args.receivable = Some(true.into());
+        self
+    }
+
+    pub fn include_receive_hash(mut self) -> Self {
+        self.args.receive_hash = Some(true.into());
+        self
+    }
+
+    pub fn include_source(mut self) -> Self {
+        self.args.source = Some(true.into());
+        self
+    }
+
+    pub fn include_not_found(mut self) -> Self {
+        self.args.include_not_found = Some(true.into());
+        self
+    }
+
+    pub fn build(self) -> BlocksInfoArgs {
+        self.args
+    }
+}
+
 impl From<Vec<BlockHash>> for BlocksInfoArgs {
     fn from(value: Vec<BlockHash>) -> Self {
         Self {
@@ -50,3 +88,51 @@ impl From<Vec<BlockHash>> for BlocksInfoArgs {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn serialize_blocks_info_command_defaults() {
+        let command = RpcCommand::blocks_info(vec![BlockHash::from(1)]);
+        assert_eq!(
+            serde_json::to_value(command).unwrap(),
+            json!({
+                "action": "blocks_info",
+                "hashes": [BlockHash::from(1)]
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_blocks_info_command_with_options() {
+        let args = BlocksInfoArgs::builder(vec![BlockHash::from(1)])
+            .include_receivable()
+            .include_source()
+            .include_not_found()
+            .build();
+        assert_eq!(
+            serde_json::to_value(RpcCommand::blocks_info(args)).unwrap(),
+            json!({
+                "action": "blocks_info",
+                "receivable": "true",
+                "source": "true",
+                "include_not_found": "true",
+                "hashes": [BlockHash::from(1)]
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_blocks_not_found() {
+        let response: BlocksInfoResponse = serde_json::from_value(json!({
+            "blocks": {},
+            "blocks_not_found": [BlockHash::from(2)]
+        }))
+        .unwrap();
+        assert!(response.blocks.is_empty());
+        assert_eq!(response.blocks_not_found, Some(vec![BlockHash::from(2)]));
+    }
+}
@@ -173,4 +173,37 @@ mod tests {
         assert_eq!(args.count, None);
         assert_eq!(args.start, None);
     }
+
+    #[test]
+    fn serialize_threshold_only_when_provided() {
+        let command = RpcCommand::Delegators(
+            DelegatorsArgs::builder(Account::zero())
+                .with_minimum_balance(Amount::raw(1000))
+                .build(),
+        );
+        let serialized = serde_json::to_value(command).unwrap();
+        assert_eq!(
+            serialized,
+            json!({
+                "action": "delegators",
+                "account": "nano_1111111111111111111111111111111111111111111111111111hifc8npp",
+                "threshold": "1000"
+            })
+        );
+
+        let command = RpcCommand::Delegators(
+            DelegatorsArgs::builder(Account::zero())
+                .start_from(Account::zero())
+                .build(),
+        );
+        let serialized = serde_json::to_value(command).unwrap();
+        assert_eq!(
+            serialized,
+            json!({
+                "action": "delegators",
+                "account": "nano_1111111111111111111111111111111111111111111111111111hifc8npp",
+                "start": "nano_1111111111111111111111111111111111111111111111111111hifc8npp"
+            })
+        );
+    }
 }
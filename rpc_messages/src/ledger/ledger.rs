@@ -153,6 +153,25 @@ mod tests {
         assert_eq!(serialized, expected);
     }
 
+    #[test]
+    fn omit_unset_ledger_args() {
+        let args = LedgerArgs::builder()
+            .sorted()
+            .with_minimum_balance(Amount::raw(1000))
+            .build();
+
+        let serialized = serde_json::to_value(RpcCommand::ledger(args)).unwrap();
+
+        assert_eq!(
+            serialized,
+            json!({
+                "action": "ledger",
+                "sorting": "true",
+                "threshold": "1000"
+            })
+        );
+    }
+
     #[test]
     fn test_ledger_rpc_command_deserialization() {
         let json_str = r#"{
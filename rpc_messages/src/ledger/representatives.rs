@@ -9,6 +9,38 @@ pub struct RepresentativesArgs {
     pub sorting: Option<RpcBool>,
 }
 
+impl RepresentativesArgs {
+    pub fn builder() -> RepresentativesArgsBuilder {
+        RepresentativesArgsBuilder {
+            args: RepresentativesArgs {
+                count: None,
+                sorting: None,
+            },
+        }
+    }
+}
+
+pub struct RepresentativesArgsBuilder {
+    args: RepresentativesArgs,
+}
+
+impl RepresentativesArgsBuilder {
+    pub fn count(mut self, count: u64) -> Self {
+        self.args.count = Some(count.into());
+        self
+    }
+
+    /// Sort the representatives by descending voting weight
+    pub fn sorted(mut self) -> Self {
+        self.args.sorting = Some(true.into());
+        self
+    }
+
+    pub fn build(self) -> RepresentativesArgs {
+        self.args
+    }
+}
+
 impl RpcCommand {
     pub fn representatives() -> Self {
         Self::Representatives(RepresentativesArgs {
@@ -21,6 +53,8 @@ impl RpcCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::RepresentativesResponse;
+    use rsnano_core::{Account, Amount};
     use serde_json::json;
 
     #[test]
@@ -65,4 +99,37 @@ mod tests {
             panic!("Deserialized to unexpected variant");
         }
     }
+
+    #[test]
+    fn build_sorted_representatives_args() {
+        let args = RepresentativesArgs::builder().count(10).sorted().build();
+        assert_eq!(
+            args,
+            RepresentativesArgs {
+                count: Some(10.into()),
+                sorting: Some(true.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialized_representatives_keep_node_order() {
+        let json = json!({
+            "representatives": {
+                "nano_1111111111111111111111111111111111111111111111111117353trpda": "3000",
+                "nano_1111111111111111111111111111111111111111111111111111hifc8npp": "2000",
+                "nano_11111111111111111111111111111111111111111111111111ros3kc7wyy": "1000"
+            }
+        });
+        let deserialized: RepresentativesResponse = serde_json::from_value(json).unwrap();
+        let weights: Vec<Amount> = deserialized.representatives.values().cloned().collect();
+        assert_eq!(
+            weights,
+            vec![Amount::raw(3000), Amount::raw(2000), Amount::raw(1000)]
+        );
+        assert_eq!(
+            deserialized.representatives.keys().nth(1),
+            Some(&Account::zero())
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::RpcU64;
+use crate::{common::deserialize_empty_as_default, RpcU64};
 use rsnano_core::{Account, Amount};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -15,6 +15,7 @@ pub struct UnopenedArgs {
 
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct UnopenedResponse {
+    #[serde(deserialize_with = "deserialize_empty_as_default")]
     pub accounts: HashMap<Account, Amount>,
 }
 
@@ -124,4 +125,30 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn deserialize_empty_string_accounts() {
+        let response: UnopenedResponse = from_value(json!({"accounts": ""})).unwrap();
+        assert_eq!(response, UnopenedResponse::new(HashMap::new()));
+    }
+
+    #[test]
+    fn deserialize_populated_accounts() {
+        let response: UnopenedResponse = from_value(json!({
+            "accounts": {
+                "nano_1111111111111111111111111111111111111111111111111111hifc8npp": "1000"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response,
+            UnopenedResponse::new(HashMap::from([(Account::zero(), Amount::raw(1000))]))
+        );
+    }
+
+    #[test]
+    fn reject_non_empty_string_accounts() {
+        assert!(from_value::<UnopenedResponse>(json!({"accounts": "foo"})).is_err());
+    }
 }
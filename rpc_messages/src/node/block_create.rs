@@ -73,6 +73,59 @@ impl BlockCreateArgs {
             difficulty: difficulty.map(|i| i.into()),
         }
     }
+
+    pub fn builder(block_type: BlockTypeDto) -> BlockCreateArgsBuilder {
+        BlockCreateArgsBuilder {
+            args: BlockCreateArgs::new(
+                block_type, None, None, None, None, None, None, None, None, None, None, None, None,
+            ),
+        }
+    }
+}
+
+pub struct BlockCreateArgsBuilder {
+    args: BlockCreateArgs,
+}
+
+impl BlockCreateArgsBuilder {
+    pub fn balance(mut self, balance: Amount) -> Self {
+        self.args.balance = Some(balance);
+        self
+    }
+
+    pub fn key(mut self, key: RawKey) -> Self {
+        self.args.key = Some(key);
+        self
+    }
+
+    pub fn account(mut self, account: Account) -> Self {
+        self.args.account = Some(account);
+        self
+    }
+
+    pub fn representative(mut self, representative: Account) -> Self {
+        self.args.representative = Some(representative);
+        self
+    }
+
+    pub fn link(mut self, link: Link) -> Self {
+        self.args.link = Some(link);
+        self
+    }
+
+    pub fn previous(mut self, previous: BlockHash) -> Self {
+        self.args.previous = Some(previous);
+        self
+    }
+
+    pub fn work(mut self, work: WorkNonce) -> Self {
+        self.args.work = Some(work);
+        self
+    }
+
+    pub fn build(self) -> BlockCreateArgs {
+        self.args
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
@@ -157,6 +210,37 @@ mod tests {
         assert_eq!(serialized_value, expected_value);
     }
 
+    #[test]
+    fn build_state_block_create_command() {
+        let key = RawKey::from(7);
+        let representative = Account::from(1);
+        let link = Link::from(2);
+        let previous = BlockHash::from(3);
+
+        let args = BlockCreateArgs::builder(BlockTypeDto::State)
+            .balance(Amount::raw(1000))
+            .key(key)
+            .representative(representative)
+            .link(link)
+            .previous(previous)
+            .build();
+
+        let serialized = serde_json::to_value(RpcCommand::block_create(args)).unwrap();
+
+        assert_eq!(
+            serialized,
+            json!({
+                "action": "block_create",
+                "type": "state",
+                "balance": "1000",
+                "key": key.encode_hex(),
+                "representative": representative.encode_account(),
+                "link": link.encode_hex(),
+                "previous": previous.encode_hex()
+            })
+        );
+    }
+
     #[test]
     fn deserialize_block_create_command() {
         let json = r#"{
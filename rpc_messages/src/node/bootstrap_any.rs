@@ -37,3 +37,18 @@ impl BootstrapAnyArgsBuilder {
         self.args
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RpcCommand;
+    use serde_json::{from_value, to_value};
+
+    #[test]
+    fn serialize_bootstrap_any_command() {
+        let cmd = RpcCommand::BootstrapAny(BootstrapAnyArgs::builder().force().build());
+        let serialized = to_value(&cmd).unwrap();
+        assert_eq!(serialized["action"], "bootstrap_any");
+        assert_eq!(from_value::<RpcCommand>(serialized).unwrap(), cmd);
+    }
+}
@@ -16,8 +16,8 @@ pub struct BootstrapLazyArgs {
 }
 
 impl BootstrapLazyArgs {
-    pub fn builder(hash: BlockHash) -> BootsrapLazyArgsBuilder {
-        BootsrapLazyArgsBuilder {
+    pub fn builder(hash: BlockHash) -> BootstrapLazyArgsBuilder {
+        BootstrapLazyArgsBuilder {
             args: BootstrapLazyArgs {
                 hash,
                 force: None,
@@ -27,11 +27,11 @@ impl BootstrapLazyArgs {
     }
 }
 
-pub struct BootsrapLazyArgsBuilder {
+pub struct BootstrapLazyArgsBuilder {
     args: BootstrapLazyArgs,
 }
 
-impl BootsrapLazyArgsBuilder {
+impl BootstrapLazyArgsBuilder {
     pub fn force(mut self) -> Self {
         self.args.force = Some(true.into());
         self
@@ -52,3 +52,18 @@ pub struct BootstrapLazyResponse {
     pub started: RpcBoolNumber,
     pub key_inserted: RpcBoolNumber,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RpcCommand;
+    use serde_json::{from_value, to_value};
+
+    #[test]
+    fn serialize_bootstrap_lazy_command() {
+        let cmd = RpcCommand::BootstrapLazy(BootstrapLazyArgs::builder(BlockHash::from(1)).build());
+        let serialized = to_value(&cmd).unwrap();
+        assert_eq!(serialized["action"], "bootstrap_lazy");
+        assert_eq!(from_value::<RpcCommand>(serialized).unwrap(), cmd);
+    }
+}
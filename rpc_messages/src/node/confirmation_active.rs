@@ -12,6 +12,7 @@ impl RpcCommand {
 
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct ConfirmationActiveArgs {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub announcements: Option<RpcU64>,
 }
 
@@ -21,3 +22,41 @@ pub struct ConfirmationActiveResponse {
     pub unconfirmed: RpcU64,
     pub confirmed: RpcU64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn serialize_confirmation_active_command() {
+        assert_eq!(
+            serde_json::to_value(RpcCommand::confirmation_active(None)).unwrap(),
+            json!({ "action": "confirmation_active" })
+        );
+        assert_eq!(
+            serde_json::to_value(RpcCommand::confirmation_active(Some(5))).unwrap(),
+            json!({ "action": "confirmation_active", "announcements": "5" })
+        );
+    }
+
+    #[test]
+    fn deserialize_confirmation_active_response() {
+        let root = QualifiedRoot::new_test_instance();
+        let response: ConfirmationActiveResponse = serde_json::from_value(json!({
+            "confirmations": [root],
+            "unconfirmed": "1",
+            "confirmed": "2"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response,
+            ConfirmationActiveResponse {
+                confirmations: vec![root],
+                unconfirmed: 1.into(),
+                confirmed: 2.into(),
+            }
+        );
+    }
+}
@@ -14,7 +14,9 @@ impl From<QualifiedRoot> for ConfirmationInfoArgs {
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct ConfirmationInfoArgs {
     pub root: QualifiedRoot,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub contents: Option<RpcBool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub representatives: Option<RpcBool>,
 }
 
@@ -66,3 +68,77 @@ pub struct ConfirmationBlockInfoDto {
     pub contents: Option<JsonBlock>,
     pub representatives: Option<IndexMap<Account, Amount>>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RpcCommand;
+    use serde_json::json;
+
+    #[test]
+    fn serialize_confirmation_info_command() {
+        let root = QualifiedRoot::new_test_instance();
+        let args = ConfirmationInfoArgs::build(root.clone())
+            .without_contents()
+            .include_representatives()
+            .finish();
+
+        assert_eq!(
+            serde_json::to_value(RpcCommand::ConfirmationInfo(args)).unwrap(),
+            json!({
+                "action": "confirmation_info",
+                "root": root,
+                "contents": "false",
+                "representatives": "true"
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_confirmation_info_command_defaults() {
+        let root = QualifiedRoot::new_test_instance();
+        assert_eq!(
+            serde_json::to_value(RpcCommand::ConfirmationInfo(root.clone().into())).unwrap(),
+            json!({ "action": "confirmation_info", "root": root })
+        );
+    }
+
+    #[test]
+    fn deserialize_confirmation_info_with_representatives() {
+        let hash = BlockHash::from(1);
+        let rep1 = Account::from(2);
+        let rep2 = Account::from(3);
+        let dto: ConfirmationInfoDto = serde_json::from_value(json!({
+            "announcements": "2",
+            "voters": "2",
+            "last_winner": hash,
+            "total_tally": "300",
+            "final_tally": "100",
+            "blocks": {
+                hash.to_string(): {
+                    "tally": "300",
+                    "representatives": {
+                        rep1.encode_account(): "200",
+                        rep2.encode_account(): "100"
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(dto.announcements, 2.into());
+        assert_eq!(dto.voters, 2.into());
+        assert_eq!(dto.last_winner, hash);
+        assert_eq!(dto.total_tally, Amount::raw(300));
+        assert_eq!(dto.final_tally, Amount::raw(100));
+
+        let block = &dto.blocks[&hash];
+        assert_eq!(block.tally, Amount::raw(300));
+        assert_eq!(block.contents, None);
+        let reps = block.representatives.as_ref().unwrap();
+        assert_eq!(
+            reps.iter().collect::<Vec<_>>(),
+            vec![(&rep1, &Amount::raw(200)), (&rep2, &Amount::raw(100))]
+        );
+    }
+}
@@ -41,3 +41,63 @@ pub struct PeerDetailsDto {
     pub ip: SocketAddrV6,
     pub weight: Amount,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn deserialize_without_peer_details() {
+        let json = json!({
+            "quorum_delta": "41469707173777717318245825935516662250",
+            "online_weight_quorum_percent": "50",
+            "online_weight_minimum": "60000000000000000000000000000000000000",
+            "online_stake_total": "82939414347555434636491651871033324568",
+            "peers_stake_total": "69026910610720098597176027400951402360",
+            "trended_stake_total": "81939414347555434636491651871033324568"
+        });
+
+        let response: ConfirmationQuorumResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            response.quorum_delta,
+            Amount::raw(41469707173777717318245825935516662250)
+        );
+        assert_eq!(response.online_weight_quorum_percent, 50.into());
+        assert_eq!(
+            response.peers_stake_total,
+            Amount::raw(69026910610720098597176027400951402360)
+        );
+        assert_eq!(response.peers, None);
+    }
+
+    #[test]
+    fn deserialize_with_peer_details() {
+        let account = Account::from(42);
+        let json = json!({
+            "quorum_delta": "1000",
+            "online_weight_quorum_percent": "50",
+            "online_weight_minimum": "2000",
+            "online_stake_total": "2000",
+            "peers_stake_total": "1500",
+            "trended_stake_total": "2000",
+            "peers": [{
+                "account": account.encode_account(),
+                "ip": "[::ffff:127.0.0.1]:7075",
+                "weight": "1500"
+            }]
+        });
+
+        let response: ConfirmationQuorumResponse = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            response.peers,
+            Some(vec![PeerDetailsDto {
+                account,
+                ip: "[::ffff:127.0.0.1]:7075".parse().unwrap(),
+                weight: Amount::raw(1500),
+            }])
+        );
+    }
+}
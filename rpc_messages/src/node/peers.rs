@@ -111,4 +111,36 @@ mod tests {
         );
         assert_eq!(peer_info.connection_type, "tcp");
     }
+
+    #[test]
+    fn deserialize_simple_peers_dto() {
+        let json = r#"{"peers":{"[::ffff:172.17.0.1]:32841":"16"}}"#;
+        let peers: PeersDto = serde_json::from_str(json).unwrap();
+
+        let PeersDto::Simple(peers) = peers else {
+            panic!("expected simple peers");
+        };
+        assert_eq!(
+            peers
+                .peers
+                .get(&"[::ffff:172.17.0.1]:32841".parse().unwrap()),
+            Some(&16.into())
+        );
+    }
+
+    #[test]
+    fn deserialize_detailed_peers_dto() {
+        let json = r#"{"peers":{"[::ffff:172.17.0.1]:7075":{"protocol_version":"18","node_id":"node_1y7j5rdqhg99uyab1145gu3yur1ax35a3b6qr417yt8cd6n86uiw3d4whty3","type":"tcp","peering":"[::1]:111"}}}"#;
+        let peers: PeersDto = serde_json::from_str(json).unwrap();
+
+        let PeersDto::Detailed(peers) = peers else {
+            panic!("expected detailed peers");
+        };
+        let peer_info = peers
+            .peers
+            .get(&"[::ffff:172.17.0.1]:7075".parse().unwrap())
+            .unwrap();
+        assert_eq!(peer_info.protocol_version, 18.into());
+        assert_eq!(peer_info.connection_type, "tcp");
+    }
 }
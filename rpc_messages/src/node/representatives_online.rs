@@ -192,4 +192,41 @@ mod tests {
         .unwrap();
         assert!(deserialized.representatives.contains(&account));
     }
+
+    #[test]
+    fn deserialize_representatives_online_response_array() {
+        let json = r#"{"representatives":["nano_114nk4rwjctu6n6tr6g6ps61g1w3hdpjxfas4xj1tq6i8jyomc5d858xr1xi"]}"#;
+        let deserialized: RepresentativesOnlineResponse = serde_json::from_str(json).unwrap();
+        let account = Account::decode_account(
+            "nano_114nk4rwjctu6n6tr6g6ps61g1w3hdpjxfas4xj1tq6i8jyomc5d858xr1xi",
+        )
+        .unwrap();
+        assert_eq!(
+            deserialized,
+            RepresentativesOnlineResponse::Simple(SimpleRepresentativesOnline {
+                representatives: vec![account]
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_representatives_online_response_weighted_map() {
+        let json = r#"{"representatives":{"nano_114nk4rwjctu6n6tr6g6ps61g1w3hdpjxfas4xj1tq6i8jyomc5d858xr1xi":{"weight":"1000"}}}"#;
+        let deserialized: RepresentativesOnlineResponse = serde_json::from_str(json).unwrap();
+        let account = Account::decode_account(
+            "nano_114nk4rwjctu6n6tr6g6ps61g1w3hdpjxfas4xj1tq6i8jyomc5d858xr1xi",
+        )
+        .unwrap();
+        let mut expected = DetailedRepresentativesOnline::default();
+        expected.representatives.insert(
+            account,
+            RepWeightDto {
+                weight: Amount::raw(1000),
+            },
+        );
+        assert_eq!(
+            deserialized,
+            RepresentativesOnlineResponse::Detailed(expected)
+        );
+    }
 }
@@ -17,8 +17,11 @@ impl From<BlockHash> for RepublishArgs {
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct RepublishArgs {
     pub hash: BlockHash,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sources: Option<RpcUsize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub destinations: Option<RpcUsize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<RpcUsize>,
 }
 
@@ -69,3 +72,51 @@ impl RepublishArgsBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlockHashesResponse;
+    use serde_json::json;
+
+    #[test]
+    fn serialize_republish_command_defaults() {
+        let hash = BlockHash::from(1);
+        assert_eq!(
+            serde_json::to_value(RpcCommand::republish(hash.into())).unwrap(),
+            json!({ "action": "republish", "hash": hash })
+        );
+    }
+
+    #[test]
+    fn serialize_republish_command_with_options() {
+        let hash = BlockHash::from(1);
+        let args = RepublishArgs::builder(hash)
+            .with_count(3)
+            .with_sources(2)
+            .with_destinations(1)
+            .build();
+        assert_eq!(
+            serde_json::to_value(RpcCommand::republish(args)).unwrap(),
+            json!({
+                "action": "republish",
+                "hash": hash,
+                "sources": "2",
+                "destinations": "1",
+                "count": "3"
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_republish_response() {
+        let response: BlockHashesResponse = serde_json::from_value(json!({
+            "blocks": [BlockHash::from(1), BlockHash::from(2)]
+        }))
+        .unwrap();
+        assert_eq!(
+            response.blocks,
+            vec![BlockHash::from(1), BlockHash::from(2)]
+        );
+    }
+}
@@ -34,6 +34,26 @@ pub struct SignArgs {
     pub hash: Option<BlockHash>,
 }
 
+impl SignArgs {
+    pub fn builder_for_block(block: JsonBlock) -> SignArgsBuilder {
+        SignArgsBuilder { args: block.into() }
+    }
+
+    pub fn builder_for_hash(hash: BlockHash) -> SignArgsBuilder {
+        SignArgsBuilder {
+            args: SignArgs {
+                block: None,
+                key: None,
+                wallet: None,
+                account: None,
+                hash: Some(hash),
+            },
+        }
+    }
+}
+
+/// Signs either with a private key or with the key of an account in a wallet.
+/// Both modes are mutually exclusive, so `build` fails if both are set.
 pub struct SignArgsBuilder {
     args: SignArgs,
 }
@@ -44,24 +64,100 @@ impl SignArgsBuilder {
         self
     }
 
-    pub fn wallet(mut self, wallet: WalletId) -> Self {
+    pub fn wallet_account(mut self, wallet: WalletId, account: Account) -> Self {
         self.args.wallet = Some(wallet);
+        self.args.account = Some(account);
         self
     }
 
-    pub fn account(mut self, account: Account) -> Self {
-        self.args.account = Some(account);
-        self
+    pub fn build(self) -> Result<SignArgs, SignArgsError> {
+        if self.args.key.is_some() && self.args.wallet.is_some() {
+            return Err(SignArgsError::KeyAndWalletAccount);
+        }
+        Ok(self.args)
     }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SignArgsError {
+    /// Only one of `key` or `wallet_account` may be used for signing
+    KeyAndWalletAccount,
+}
 
-    pub fn build(self) -> SignArgs {
-        self.args
+impl std::fmt::Display for SignArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::KeyAndWalletAccount => {
+                write!(f, "key and wallet account are mutually exclusive")
+            }
+        }
     }
 }
 
+impl std::error::Error for SignArgsError {}
+
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct SignResponse {
     pub signature: Signature,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub block: Option<JsonBlock>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, to_value};
+
+    #[test]
+    fn serialize_sign_hash_with_key() {
+        let key = RawKey::from(1);
+        let hash = BlockHash::from(2);
+        let args = SignArgs::builder_for_hash(hash).key(key).build().unwrap();
+
+        assert_eq!(
+            to_value(RpcCommand::sign(args)).unwrap(),
+            json!({
+                "action": "sign",
+                "key": key.encode_hex(),
+                "hash": hash.encode_hex()
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_sign_hash_with_wallet_account() {
+        let wallet = WalletId::from(3);
+        let account = Account::from(4);
+        let hash = BlockHash::from(2);
+        let args = SignArgs::builder_for_hash(hash)
+            .wallet_account(wallet, account)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            to_value(RpcCommand::sign(args)).unwrap(),
+            json!({
+                "action": "sign",
+                "wallet": wallet.encode_hex(),
+                "account": account.encode_account(),
+                "hash": hash.encode_hex()
+            })
+        );
+    }
+
+    #[test]
+    fn key_and_wallet_are_mutually_exclusive() {
+        let hash = BlockHash::from(2);
+        let result = SignArgs::builder_for_hash(hash)
+            .wallet_account(WalletId::from(3), Account::from(4))
+            .key(RawKey::from(1))
+            .build();
+        assert_eq!(result, Err(SignArgsError::KeyAndWalletAccount));
+
+        let result = SignArgs::builder_for_hash(hash)
+            .key(RawKey::from(1))
+            .wallet_account(WalletId::from(3), Account::from(4))
+            .build();
+        assert_eq!(result, Err(SignArgsError::KeyAndWalletAccount));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::{RpcI64, RpcU64};
 use serde::{Deserialize, Serialize};
 
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
@@ -6,7 +7,7 @@ pub struct StatsArgs {
     pub stats_type: StatsType,
 }
 
-#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum StatsType {
     Counters,
@@ -14,3 +15,138 @@ pub enum StatsType {
     Samples,
     Database,
 }
+
+/// Stats dump of the types `counters` and `samples`.
+/// `type` and `created` are only present if the node logs stats headers.
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct StatsResponse {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub stats_type: Option<StatsType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    pub entries: Vec<StatsEntry>,
+    pub stat_duration_seconds: RpcU64,
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum StatsEntry {
+    Counter(StatsCounterEntry),
+    Sample(StatsSampleEntry),
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct StatsCounterEntry {
+    pub time: String,
+    #[serde(rename = "type")]
+    pub stat_type: String,
+    pub detail: String,
+    pub dir: String,
+    pub value: RpcU64,
+}
+
+#[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct StatsSampleEntry {
+    pub time: String,
+    pub sample: String,
+    pub min: RpcI64,
+    pub max: RpcI64,
+    pub values: Vec<RpcI64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RpcCommand;
+    use serde_json::json;
+
+    #[test]
+    fn serialize_stats_command() {
+        let command = RpcCommand::Stats(StatsArgs {
+            stats_type: StatsType::Samples,
+        });
+        assert_eq!(
+            serde_json::to_value(command).unwrap(),
+            json!({ "action": "stats", "type": "samples" })
+        );
+    }
+
+    #[test]
+    fn deserialize_counters() {
+        let response: StatsResponse = serde_json::from_value(json!({
+            "type": "counters",
+            "created": "2024.10.15 10:15:00",
+            "entries": [
+                {
+                    "time": "10:14:59",
+                    "type": "ledger",
+                    "detail": "all",
+                    "dir": "in",
+                    "value": "42"
+                }
+            ],
+            "stat_duration_seconds": "120"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response,
+            StatsResponse {
+                stats_type: Some(StatsType::Counters),
+                created: Some("2024.10.15 10:15:00".to_owned()),
+                entries: vec![StatsEntry::Counter(StatsCounterEntry {
+                    time: "10:14:59".to_owned(),
+                    stat_type: "ledger".to_owned(),
+                    detail: "all".to_owned(),
+                    dir: "in".to_owned(),
+                    value: 42.into(),
+                })],
+                stat_duration_seconds: 120.into(),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_samples() {
+        let response: StatsResponse = serde_json::from_value(json!({
+            "type": "samples",
+            "created": "2024.10.15 10:15:00",
+            "entries": [
+                {
+                    "time": "10:14:59",
+                    "sample": "active_election_duration",
+                    "min": "0",
+                    "max": "60000",
+                    "values": ["250", "1200"]
+                }
+            ],
+            "stat_duration_seconds": "120"
+        }))
+        .unwrap();
+
+        assert_eq!(response.stats_type, Some(StatsType::Samples));
+        assert_eq!(
+            response.entries,
+            vec![StatsEntry::Sample(StatsSampleEntry {
+                time: "10:14:59".to_owned(),
+                sample: "active_election_duration".to_owned(),
+                min: 0.into(),
+                max: 60000.into(),
+                values: vec![250.into(), 1200.into()],
+            })]
+        );
+    }
+
+    #[test]
+    fn deserialize_counters_without_header() {
+        let response: StatsResponse = serde_json::from_value(json!({
+            "entries": [],
+            "stat_duration_seconds": "0"
+        }))
+        .unwrap();
+
+        assert_eq!(response.stats_type, None);
+        assert_eq!(response.created, None);
+        assert!(response.entries.is_empty());
+    }
+}
@@ -279,4 +279,40 @@ mod tests {
         assert_eq!(dto.address, None);
         assert_eq!(dto.port, None);
     }
+
+    #[test]
+    fn deserialize_aggregated_telemetry_payload() {
+        let json = r#"{
+            "block_count": "5777903",
+            "cemented_count": "688819",
+            "unchecked_count": "443468",
+            "account_count": "620750",
+            "bandwidth_cap": "1572864",
+            "peer_count": "32",
+            "protocol_version": "18",
+            "uptime": "556896",
+            "genesis_block": "991CF190094C00F0B68E2E5F75F6BEE95A2E0BD93CEAA4A6734DB9F19B728948",
+            "major_version": "21",
+            "minor_version": "0",
+            "patch_version": "0",
+            "pre_release_version": "0",
+            "maker": "0",
+            "timestamp": "1587055945990",
+            "active_difficulty": "ffffffcdbf40aa45"
+        }"#;
+
+        let response: TelemetryResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.metrics.len(), 1);
+        let telemetry = &response.metrics[0];
+        assert_eq!(telemetry.block_count, 5777903.into());
+        assert_eq!(telemetry.cemented_count, 688819.into());
+        assert_eq!(telemetry.peer_count, 32.into());
+        assert_eq!(telemetry.protocol_version, 18.into());
+        assert_eq!(telemetry.uptime, 556896.into());
+        assert_eq!(telemetry.bandwidth_cap, 1572864.into());
+        assert_eq!(telemetry.active_difficulty, "ffffffcdbf40aa45");
+        assert_eq!(telemetry.signature, None);
+        assert_eq!(telemetry.node_id, None);
+    }
 }
@@ -21,3 +21,33 @@ impl UncheckedResponse {
         Self { blocks }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnano_core::Block;
+    use serde_json::json;
+
+    #[test]
+    fn serialize_unchecked_command() {
+        assert_eq!(
+            serde_json::to_value(RpcCommand::unchecked(10)).unwrap(),
+            json!({ "action": "unchecked", "count": "10" })
+        );
+    }
+
+    #[test]
+    fn deserialize_unchecked_response() {
+        let block = Block::new_test_instance();
+        let hash = block.hash();
+        let response: UncheckedResponse = serde_json::from_value(json!({
+            "blocks": { hash.to_string(): block.json_representation() }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response,
+            UncheckedResponse::new(HashMap::from([(hash, block.json_representation())]))
+        );
+    }
+}
@@ -15,3 +15,37 @@ pub struct UncheckedGetResponse {
     pub modified_timestamp: RpcU64,
     pub contents: JsonBlock,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnano_core::Block;
+    use serde_json::json;
+
+    #[test]
+    fn serialize_unchecked_get_command() {
+        let hash = BlockHash::from(1);
+        assert_eq!(
+            serde_json::to_value(RpcCommand::unchecked_get(hash)).unwrap(),
+            json!({ "action": "unchecked_get", "hash": hash })
+        );
+    }
+
+    #[test]
+    fn deserialize_unchecked_get_response() {
+        let block = Block::new_test_instance();
+        let response: UncheckedGetResponse = serde_json::from_value(json!({
+            "modified_timestamp": "1565856525",
+            "contents": block.json_representation()
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response,
+            UncheckedGetResponse {
+                modified_timestamp: 1565856525.into(),
+                contents: block.json_representation(),
+            }
+        );
+    }
+}
@@ -15,6 +15,7 @@ impl RpcCommand {
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub struct UncheckedKeysArgs {
     pub key: HashOrAccount,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub count: Option<RpcU64>,
 }
 
@@ -36,3 +37,47 @@ pub struct UncheckedKeyDto {
     pub modified_timestamp: RpcU64,
     pub contents: JsonBlock,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsnano_core::Block;
+    use serde_json::json;
+
+    #[test]
+    fn serialize_unchecked_keys_command() {
+        let key = HashOrAccount::from(1);
+        assert_eq!(
+            serde_json::to_value(RpcCommand::unchecked_keys(key, None)).unwrap(),
+            json!({ "action": "unchecked_keys", "key": key })
+        );
+        assert_eq!(
+            serde_json::to_value(RpcCommand::unchecked_keys(key, Some(5))).unwrap(),
+            json!({ "action": "unchecked_keys", "key": key, "count": "5" })
+        );
+    }
+
+    #[test]
+    fn deserialize_unchecked_keys_response() {
+        let block = Block::new_test_instance();
+        let response: UncheckedKeysResponse = serde_json::from_value(json!({
+            "unchecked": [{
+                "key": BlockHash::from(1),
+                "hash": block.hash(),
+                "modified_timestamp": "1565856744",
+                "contents": block.json_representation()
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response,
+            UncheckedKeysResponse::new(vec![UncheckedKeyDto {
+                key: BlockHash::from(1),
+                hash: block.hash(),
+                modified_timestamp: 1565856744.into(),
+                contents: block.json_representation(),
+            }])
+        );
+    }
+}
@@ -22,7 +22,7 @@ impl UptimeResponse {
 
 #[cfg(test)]
 mod tests {
-    use crate::RpcCommand;
+    use super::*;
     use serde_json::{from_str, to_string_pretty};
 
     #[test]
@@ -42,4 +42,16 @@ mod tests {
         let deserialized: RpcCommand = from_str(&serialized).unwrap();
         assert_eq!(cmd, deserialized);
     }
+
+    #[test]
+    fn deserialize_uptime_response() {
+        let deserialized: UptimeResponse = from_str(r#"{"seconds": "6000"}"#).unwrap();
+        assert_eq!(deserialized, UptimeResponse::new(6000));
+        assert_eq!(deserialized.seconds.inner(), 6000u64);
+    }
+
+    #[test]
+    fn reject_non_numeric_uptime() {
+        assert!(from_str::<UptimeResponse>(r#"{"seconds": "abc"}"#).is_err());
+    }
 }
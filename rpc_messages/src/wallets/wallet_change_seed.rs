@@ -114,6 +114,28 @@ mod tests {
         assert_eq!(command, deserialized);
     }
 
+    #[test]
+    fn serialize_wallet_change_seed_command_with_count() {
+        let args = WalletChangeSeedArgs::builder(WalletId::from(1), RawKey::from(2))
+            .count(10)
+            .build();
+
+        assert_eq!(
+            serde_json::to_string(&RpcCommand::wallet_change_seed(args)).unwrap(),
+            r#"{"action":"wallet_change_seed","wallet":"0000000000000000000000000000000000000000000000000000000000000001","seed":"0000000000000000000000000000000000000000000000000000000000000002","count":"10"}"#
+        );
+    }
+
+    #[test]
+    fn serialize_wallet_change_seed_command_without_count() {
+        let args = WalletChangeSeedArgs::new(WalletId::from(1), RawKey::from(2));
+
+        assert_eq!(
+            serde_json::to_string(&RpcCommand::wallet_change_seed(args)).unwrap(),
+            r#"{"action":"wallet_change_seed","wallet":"0000000000000000000000000000000000000000000000000000000000000001","seed":"0000000000000000000000000000000000000000000000000000000000000002"}"#
+        );
+    }
+
     #[test]
     fn deserialize_wallet_change_seed_command() {
         let json = r#"{"action":"wallet_change_seed","wallet":"000D1BAEC8EC208142C99059B393051BAC8380F9B5A2E6B2489A277D81789F3F","seed":"74F2B37AAD20F4A260F0A5B3CB3D7FB51673212263E58A380BC10474BB039CEE"}"#;
@@ -91,3 +91,55 @@ pub struct AccountInfo {
     pub pending: Option<Amount>,
     pub receivable: Option<Amount>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::to_string_pretty;
+
+    #[test]
+    fn serialize_wallet_ledger_without_options() {
+        assert_eq!(
+            to_string_pretty(&RpcCommand::wallet_ledger(WalletId::from(1).into())).unwrap(),
+            r#"{
+  "action": "wallet_ledger",
+  "wallet": "0000000000000000000000000000000000000000000000000000000000000001"
+}"#
+        )
+    }
+
+    #[test]
+    fn serialize_wallet_ledger_with_options() {
+        let args = WalletLedgerArgs::builder(1.into())
+            .representative()
+            .weight()
+            .receivable()
+            .modified_since(1625097600)
+            .build();
+
+        assert_eq!(
+            to_string_pretty(&RpcCommand::wallet_ledger(args)).unwrap(),
+            r#"{
+  "action": "wallet_ledger",
+  "wallet": "0000000000000000000000000000000000000000000000000000000000000001",
+  "representative": "true",
+  "weight": "true",
+  "receivable": "true",
+  "modified_since": "1625097600"
+}"#
+        )
+    }
+
+    #[test]
+    fn deserialize_wallet_ledger_with_options() {
+        let cmd = RpcCommand::wallet_ledger(
+            WalletLedgerArgs::builder(1.into())
+                .representative()
+                .weight()
+                .build(),
+        );
+        let serialized = to_string_pretty(&cmd).unwrap();
+        let deserialized: RpcCommand = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(cmd, deserialized)
+    }
+}
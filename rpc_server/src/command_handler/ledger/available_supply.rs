@@ -1,9 +1,9 @@
 use crate::command_handler::RpcCommandHandler;
 use rsnano_core::{Account, Amount};
-use rsnano_rpc_messages::AvailableSupplyReponse;
+use rsnano_rpc_messages::AvailableSupplyResponse;
 
 impl RpcCommandHandler {
-    pub(crate) fn available_supply(&self) -> AvailableSupplyReponse {
+    pub(crate) fn available_supply(&self) -> AvailableSupplyResponse {
         let tx = self.node.store.env.tx_begin_read();
         // Cold storage genesis
         let genesis_balance = self
@@ -38,6 +38,6 @@ impl RpcCommandHandler {
 
         let available =
             Amount::MAX - genesis_balance - landing_balance - faucet_balance - burned_balance;
-        AvailableSupplyReponse::new(available)
+        AvailableSupplyResponse::new(available)
     }
 }
@@ -6,16 +6,15 @@ impl RpcCommandHandler {
     pub(crate) fn wallet_change_seed(
         &self,
         args: WalletChangeSeedArgs,
-    ) -> WalletChangeSeedResponse {
-        let (restored_count, last_restored_account) = self
-            .node
-            .wallets
-            .change_seed(
-                args.wallet,
-                &args.seed,
-                args.count.unwrap_or_default().inner(),
-            )
-            .unwrap();
-        WalletChangeSeedResponse::new(last_restored_account, restored_count)
+    ) -> anyhow::Result<WalletChangeSeedResponse> {
+        let (restored_count, last_restored_account) = self.node.wallets.change_seed(
+            args.wallet,
+            &args.seed,
+            args.count.unwrap_or_default().inner(),
+        )?;
+        Ok(WalletChangeSeedResponse::new(
+            last_restored_account,
+            restored_count,
+        ))
     }
 }
@@ -63,3 +63,29 @@ fn account_info() {
     assert_eq!(result.confirmed_receivable, Some(Amount::raw(0)));
     assert_eq!(result.confirmed_representative, Some(*DEV_GENESIS_ACCOUNT));
 }
+
+#[test]
+fn account_info_without_options() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .account_info(*DEV_GENESIS_ACCOUNT)
+            .await
+            .unwrap()
+    });
+
+    assert_eq!(result.frontier, *DEV_GENESIS_HASH);
+    assert_eq!(result.balance, Amount::MAX);
+    assert_eq!(result.confirmed_height, Some(1.into()));
+    assert_eq!(result.confirmation_height_frontier, Some(*DEV_GENESIS_HASH));
+    assert_eq!(result.confirmed_frontier, None);
+    assert_eq!(result.confirmed_balance, None);
+    assert_eq!(result.representative, None);
+    assert_eq!(result.weight, None);
+    assert_eq!(result.receivable, None);
+}
@@ -1,3 +1,4 @@
+use rsnano_core::Account;
 use rsnano_ledger::DEV_GENESIS_ACCOUNT;
 use rsnano_rpc_messages::AccountsRepresentativesResponse;
 use std::collections::HashMap;
@@ -27,3 +28,31 @@ fn accounts_representatives() {
     };
     assert_eq!(result, expected);
 }
+
+#[test]
+fn accounts_representatives_with_unopened_account() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let unopened = Account::from(42);
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .accounts_representatives(vec![*DEV_GENESIS_ACCOUNT, unopened])
+            .await
+            .unwrap()
+    });
+
+    let mut representatives = HashMap::new();
+    representatives.insert(*DEV_GENESIS_ACCOUNT, *DEV_GENESIS_ACCOUNT);
+    let mut errors = HashMap::new();
+    errors.insert(unopened, "Account not found".to_string());
+
+    let expected = AccountsRepresentativesResponse {
+        representatives: Some(representatives),
+        errors: Some(errors),
+    };
+    assert_eq!(result, expected);
+}
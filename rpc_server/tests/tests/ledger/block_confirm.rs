@@ -1,5 +1,6 @@
 use rsnano_core::BlockHash;
 use rsnano_ledger::DEV_GENESIS_HASH;
+use rsnano_rpc_client::NodeRpcError;
 use test_helpers::{setup_rpc_client_and_server, System};
 
 #[test]
@@ -31,8 +32,13 @@ fn block_confirm_fails_with_block_not_found() {
         .runtime
         .block_on(async { server.client.block_confirm(BlockHash::zero()).await });
 
+    let error = result.unwrap_err();
     assert_eq!(
-        result.err().map(|e| e.to_string()),
-        Some("node returned error: \"Block not found\"".to_string())
+        error.downcast_ref::<NodeRpcError>(),
+        Some(&NodeRpcError::BlockNotFound)
+    );
+    assert_eq!(
+        error.to_string(),
+        "node returned error: \"Block not found\""
     );
 }
@@ -1,4 +1,6 @@
+use rsnano_core::BlockHash;
 use rsnano_ledger::DEV_GENESIS_HASH;
+use rsnano_rpc_messages::BlocksInfoArgs;
 use test_helpers::{setup_rpc_client_and_server, System};
 
 #[test]
@@ -8,11 +10,71 @@ fn blocks_info() {
 
     let server = setup_rpc_client_and_server(node.clone(), false);
 
-    node.runtime.block_on(async {
+    let result = node.runtime.block_on(async {
         server
             .client
             .blocks_info(vec![*DEV_GENESIS_HASH])
             .await
             .unwrap()
     });
+
+    assert!(result.blocks.contains_key(&DEV_GENESIS_HASH));
+    assert_eq!(result.blocks_not_found, None);
+}
+
+#[test]
+fn blocks_info_fails_for_unknown_block() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.blocks_info(vec![BlockHash::from(42)]).await });
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn blocks_info_include_not_found() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    let missing = BlockHash::from(42);
+    let args = BlocksInfoArgs::builder(vec![*DEV_GENESIS_HASH, missing])
+        .include_not_found()
+        .build();
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.blocks_info(args).await.unwrap() });
+
+    assert_eq!(result.blocks.len(), 1);
+    assert!(result.blocks.contains_key(&DEV_GENESIS_HASH));
+    assert_eq!(result.blocks_not_found, Some(vec![missing]));
+}
+
+#[test]
+fn blocks_info_source_and_receivable() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    let args = BlocksInfoArgs::builder(vec![*DEV_GENESIS_HASH])
+        .include_receivable()
+        .include_source()
+        .build();
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.blocks_info(args).await.unwrap() });
+
+    let info = &result.blocks[&DEV_GENESIS_HASH];
+    assert_eq!(info.receivable, Some(0.into()));
+    assert_eq!(info.source_account, Some("0".to_string()));
+    assert_eq!(info.receive_hash, None);
 }
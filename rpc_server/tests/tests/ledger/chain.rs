@@ -153,3 +153,48 @@ fn chain_offset() {
     assert_eq!(blocks.len(), 1);
     assert_eq!(blocks[0], genesis);
 }
+
+#[test]
+fn chain_reverse() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let wallet_id = WalletId::zero();
+    node.wallets.create(wallet_id);
+    node.wallets
+        .insert_adhoc2(&wallet_id, &DEV_GENESIS_KEY.raw_key(), true)
+        .unwrap();
+
+    let genesis = node.latest(&*DEV_GENESIS_ACCOUNT);
+    assert!(!genesis.is_zero());
+
+    let key = PrivateKey::new();
+    let block = node
+        .wallets
+        .send_action2(
+            &wallet_id,
+            *DEV_GENESIS_ACCOUNT,
+            key.account(),
+            Amount::raw(1),
+            0,
+            true,
+            None,
+        )
+        .unwrap();
+
+    assert_timely_msg(
+        Duration::from_secs(5),
+        || node.active.active(&block),
+        "block not active on node",
+    );
+
+    let args = ChainArgs::builder(genesis, u64::MAX).reverse().build();
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.chain(args).await.unwrap() });
+
+    assert_eq!(result.blocks, vec![genesis, block.hash()]);
+}
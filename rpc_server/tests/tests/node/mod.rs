@@ -11,6 +11,7 @@ mod receivable_exists;
 mod representatives_online;
 mod republish;
 mod sign;
+mod stats;
 mod stats_clear;
 mod stop;
 mod telemetry;
@@ -1,3 +1,4 @@
+use rsnano_rpc_client::NodeRpcError;
 use serde_json::to_string;
 use test_helpers::{setup_rpc_client_and_server, System};
 
@@ -14,3 +15,21 @@ fn populate_backlog() {
 
     assert_eq!(to_string(&result).unwrap(), r#"{"success":""}"#.to_string());
 }
+
+#[test]
+fn populate_backlog_fails_without_enable_control() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.populate_backlog().await });
+
+    let error = result.unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<NodeRpcError>(),
+        Some(&NodeRpcError::ControlDisabled)
+    );
+}
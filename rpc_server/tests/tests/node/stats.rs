@@ -0,0 +1,38 @@
+use rsnano_node::stats::{DetailType, StatType};
+use rsnano_rpc_messages::{StatsEntry, StatsType};
+use test_helpers::{setup_rpc_client_and_server, System};
+
+#[test]
+fn stats_counters() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    node.stats.add(StatType::Ledger, DetailType::Fork, 3);
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.stats(StatsType::Counters).await.unwrap() });
+
+    assert_eq!(result.stats_type, Some(StatsType::Counters));
+    let fork_count = result.entries.iter().find_map(|entry| match entry {
+        StatsEntry::Counter(c) if c.stat_type == "ledger" && c.detail == "fork" => Some(c.value),
+        _ => None,
+    });
+    assert_eq!(fork_count, Some(3.into()));
+}
+
+#[test]
+fn stats_objects_need_raw_request() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    node.runtime.block_on(async {
+        assert!(server.client.stats(StatsType::Objects).await.is_err());
+        let raw = server.client.stats_raw(StatsType::Objects).await.unwrap();
+        assert!(raw.get("node").is_some());
+    });
+}
@@ -10,13 +10,18 @@ fn block_hash() {
 
     let block = Block::new_test_instance().json_representation();
 
-    let result = node
-        .runtime
-        .block_on(async { server.client.block_hash(block).await.unwrap() });
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .block_hash_remote(block.clone())
+            .await
+            .unwrap()
+    });
 
     assert_eq!(
         result.hash,
         BlockHash::decode_hex("D9E4A975D8C4E7FE6F3569B6B60EE19D7C090C5B6E316416DC36F8C90264DF60")
             .unwrap()
     );
+    assert_eq!(server.client.block_hash_local(block), result.hash);
 }
@@ -1,6 +1,7 @@
 use rsnano_core::{Amount, UnsavedBlockLatticeBuilder, WalletId, DEV_GENESIS_KEY};
 use rsnano_ledger::BlockStatus;
 use rsnano_node::{wallets::WalletsExt, Node};
+use rsnano_rpc_client::NodeRpcError;
 use std::{sync::Arc, time::Duration};
 use test_helpers::{assert_timely_eq, setup_rpc_client_and_server, System};
 
@@ -46,8 +47,13 @@ fn search_receivable_all_fails_without_enable_control() {
         .runtime
         .block_on(async { server.client.search_receivable_all().await });
 
+    let error = result.unwrap_err();
     assert_eq!(
-        result.err().map(|e| e.to_string()),
-        Some("node returned error: \"RPC control is disabled\"".to_string())
+        error.downcast_ref::<NodeRpcError>(),
+        Some(&NodeRpcError::ControlDisabled)
+    );
+    assert_eq!(
+        error.to_string(),
+        "node returned error: \"RPC control is disabled\""
     );
 }
@@ -48,3 +48,29 @@ fn wallet_add_watch_without_enable_control() {
         Some("node returned error: \"RPC control is disabled\"".to_string())
     );
 }
+
+#[test]
+fn wallet_add_watch_with_locked_wallet() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let wallet_id = WalletId::zero();
+
+    node.wallets.create(wallet_id);
+    node.wallets.lock(&wallet_id).unwrap();
+
+    node.runtime.block_on(async {
+        server
+            .client
+            .wallet_add_watch(wallet_id, vec![*DEV_GENESIS_ACCOUNT])
+            .await
+            .unwrap()
+    });
+
+    assert_eq!(
+        node.wallets.get_accounts_of_wallet(&wallet_id).unwrap(),
+        vec![*DEV_GENESIS_ACCOUNT]
+    );
+}
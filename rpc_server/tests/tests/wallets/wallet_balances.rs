@@ -112,3 +112,20 @@ fn wallet_balances_threshold_some_fails() {
 
     assert_eq!(result, expected_result);
 }
+
+#[test]
+fn wallet_balances_fails_with_wallet_not_found() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.wallet_balances(WalletId::zero()).await });
+
+    assert_eq!(
+        result.err().map(|e| e.to_string()),
+        Some("node returned error: \"Wallet not found\"".to_string())
+    );
+}
@@ -1,6 +1,6 @@
 use rsnano_core::{RawKey, WalletId};
 use rsnano_node::wallets::WalletsExt;
-use rsnano_rpc_messages::WalletWithSeedArgs;
+use rsnano_rpc_messages::{WalletChangeSeedArgs, WalletWithSeedArgs};
 use test_helpers::{setup_rpc_client_and_server, System};
 
 #[test]
@@ -46,3 +46,56 @@ fn wallet_change_seed_fails_without_enable_control() {
         Some("node returned error: \"RPC control is disabled\"".to_string())
     );
 }
+
+#[test]
+fn wallet_change_seed_with_count() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let wallet_id = WalletId::zero();
+    node.wallets.create(wallet_id);
+
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .wallet_change_seed(
+                WalletChangeSeedArgs::builder(wallet_id, RawKey::from(42))
+                    .count(3)
+                    .build(),
+            )
+            .await
+            .unwrap()
+    });
+
+    // the first account is always restored, followed by `count` more
+    assert_eq!(result.restored_count, 4.into());
+    assert_eq!(
+        node.wallets
+            .get_accounts_of_wallet(&wallet_id)
+            .unwrap()
+            .len(),
+        4
+    );
+}
+
+#[test]
+fn wallet_change_seed_fails_with_wallet_not_found() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let result = node.runtime.block_on(async {
+        server
+            .client
+            .wallet_change_seed(WalletWithSeedArgs::new(WalletId::zero(), RawKey::zero()))
+            .await
+    });
+
+    assert_eq!(
+        result.err().map(|e| e.to_string()),
+        Some("node returned error: \"Wallet not found\"".to_string())
+    );
+}
@@ -16,5 +16,22 @@ fn wallet_export() {
         .runtime
         .block_on(async { server.client.wallet_export(wallet).await.unwrap() });
 
-    assert_ne!(result.json, "");
+    assert_ne!(result, "");
+}
+
+#[test]
+fn wallet_export_json() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), false);
+
+    let wallet = WalletId::zero();
+    node.wallets.create(wallet);
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.wallet_export_json(wallet).await.unwrap() });
+
+    assert!(result.is_object());
 }
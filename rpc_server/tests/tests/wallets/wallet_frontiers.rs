@@ -1,6 +1,7 @@
 use rsnano_core::{WalletId, DEV_GENESIS_KEY};
 use rsnano_ledger::DEV_GENESIS_ACCOUNT;
 use rsnano_node::wallets::WalletsExt;
+use std::collections::HashMap;
 use test_helpers::{send_block, setup_rpc_client_and_server, System};
 
 #[test]
@@ -32,3 +33,20 @@ fn wallet_frontiers() {
         &hash
     );
 }
+
+#[test]
+fn wallet_frontiers_empty_wallet() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let wallet = WalletId::zero();
+    node.wallets.create(wallet);
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.wallet_frontiers(wallet).await.unwrap() });
+
+    assert_eq!(result.frontiers, Some(HashMap::new()));
+}
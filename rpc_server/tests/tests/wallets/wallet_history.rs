@@ -1,7 +1,7 @@
 use rsnano_core::{Amount, BlockHash, PrivateKey, UnsavedBlockLatticeBuilder, WalletId};
 use rsnano_ledger::DEV_GENESIS_ACCOUNT;
 use rsnano_node::{wallets::WalletsExt, Node};
-use rsnano_rpc_messages::BlockTypeDto;
+use rsnano_rpc_messages::{BlockTypeDto, WalletHistoryArgs};
 use std::sync::Arc;
 use test_helpers::{setup_rpc_client_and_server, System};
 
@@ -72,3 +72,33 @@ fn wallet_history_fails_with_wallet_not_found() {
         Some("node returned error: \"Wallet not found\"".to_string())
     );
 }
+
+#[test]
+fn wallet_history_modified_since() {
+    let mut system = System::new();
+    let node = system.build_node().finish();
+    let keys = PrivateKey::new();
+    setup_test_environment(node.clone(), keys.clone(), Amount::from(100));
+
+    let wallet_id = WalletId::zero();
+    node.wallets.create(wallet_id);
+    node.wallets
+        .insert_adhoc2(&wallet_id, &keys.raw_key(), true)
+        .unwrap();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let wallet_history = node.runtime.block_on(async {
+        server
+            .client
+            .wallet_history(
+                WalletHistoryArgs::builder(wallet_id)
+                    .modified_since(u64::MAX)
+                    .build(),
+            )
+            .await
+            .unwrap()
+    });
+
+    assert!(wallet_history.history.is_empty());
+}
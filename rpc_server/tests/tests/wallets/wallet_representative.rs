@@ -38,3 +38,24 @@ fn wallet_representative_fails_with_wallet_not_found() {
         Some("node returned error: \"Wallet not found\"".to_string())
     );
 }
+
+#[test]
+fn wallet_representative_after_change() {
+    let mut system = System::new();
+    let node = system.make_node();
+
+    let server = setup_rpc_client_and_server(node.clone(), true);
+
+    let wallet = WalletId::zero();
+    let representative = PublicKey::from(42);
+    node.wallets.create(wallet);
+    node.wallets
+        .set_representative(wallet, representative, false)
+        .unwrap();
+
+    let result = node
+        .runtime
+        .block_on(async { server.client.wallet_representative(wallet).await.unwrap() });
+
+    assert_eq!(result.representative, representative.into());
+}
@@ -39,7 +39,7 @@ use rsnano_nullable_lmdb::{
 };
 pub use store::{create_backup_file, LedgerCache, LmdbStore};
 pub use version_store::LmdbVersionStore;
-pub use wallet_store::{Fans, KeyType, LmdbWalletStore, WalletValue};
+pub use wallet_store::{Fans, KeyType, LmdbWalletStore, RekeyError, WalletValue};
 
 use primitive_types::U256;
 use rsnano_core::utils::get_cpu_count;
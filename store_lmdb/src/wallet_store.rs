@@ -80,6 +80,26 @@ pub enum KeyType {
     Deterministic,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RekeyError {
+    InvalidPassword,
+    KdfFailure,
+    Io,
+}
+
+impl std::fmt::Display for RekeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            RekeyError::InvalidPassword => "invalid password",
+            RekeyError::KdfFailure => "key derivation failed",
+            RekeyError::Io => "could not write wallet key",
+        };
+        f.write_str(text)
+    }
+}
+
+impl std::error::Error for RekeyError {}
+
 pub struct LmdbWalletStore {
     db_handle: Mutex<Option<LmdbDatabase>>,
     pub fans: Mutex<Fans>,
@@ -362,24 +382,30 @@ impl LmdbWalletStore {
         self.kdf.hash_password(password, salt.as_bytes())
     }
 
-    pub fn rekey(&self, txn: &mut LmdbWriteTransaction, password: &str) -> anyhow::Result<()> {
+    pub fn rekey(&self, txn: &mut LmdbWriteTransaction, password: &str) -> Result<(), RekeyError> {
         let mut guard = self.fans.lock().unwrap();
-        if self.valid_password_locked(&guard, txn) {
-            let password_new = self.derive_key(txn, password);
-            let wallet_key = self.wallet_key_locked(&guard, txn);
-            guard.password.value_set(password_new);
-            let iv = self.salt(txn).initialization_vector_low();
-            let encrypted = wallet_key.encrypt(&password_new, &iv);
-            guard.wallet_key_mem.value_set(encrypted);
-            self.entry_put_raw(
-                txn,
-                &Self::wallet_key_special(),
-                &WalletValue::new(encrypted, 0),
-            );
-            Ok(())
-        } else {
-            Err(anyhow!("invalid password"))
+        if !self.valid_password_locked(&guard, txn) {
+            return Err(RekeyError::InvalidPassword);
         }
+
+        let salt = self.salt(txn);
+        let password_new = self
+            .kdf
+            .try_hash_password(password, salt.as_bytes())
+            .map_err(|_| RekeyError::KdfFailure)?;
+        let wallet_key = self.wallet_key_locked(&guard, txn);
+        let iv = salt.initialization_vector_low();
+        let encrypted = wallet_key.encrypt(&password_new, &iv);
+        txn.put(
+            self.db_handle(),
+            Self::wallet_key_special().as_bytes(),
+            &WalletValue::new(encrypted, 0).to_bytes(),
+            WriteFlags::empty(),
+        )
+        .map_err(|_| RekeyError::Io)?;
+        guard.password.value_set(password_new);
+        guard.wallet_key_mem.value_set(encrypted);
+        Ok(())
     }
 
     pub fn iter<'tx>(